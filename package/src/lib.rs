@@ -76,14 +76,46 @@ pub(crate) mod structs;
 
 pub(crate) mod util;
 
+pub use crate::structs::boundary::Boundary;
+
+pub use crate::structs::cancel_token::CancelToken;
+
+pub use crate::structs::direction::Direction;
+
+pub use crate::structs::error::GetDirError;
+
+pub use crate::structs::follow_links::FollowLinks;
+
+pub use crate::structs::matched::Match;
+
+pub use crate::structs::outcome::Outcome;
+
+pub use crate::structs::strategy::Strategy;
+
+pub use crate::structs::target::case::Case;
+
+pub use crate::structs::target::content::Content;
+
+pub use crate::structs::target::custom::CustomTarget;
+
 pub use crate::structs::target::dir::DirTarget;
 
-pub use crate::structs::target::file::FileTarget;
+pub use crate::structs::target::entry::{EntryKind, EntryTarget};
+
+pub use crate::structs::target::file::{DEFAULT_READ_LIMIT, FileTarget};
+
+pub use crate::structs::target::metadata::MetadataConstraints;
+
+pub use crate::structs::target::structured::{Format, Structured};
+
+pub use crate::structs::target::syntax::Syntax;
 
 pub use crate::structs::target::Target;
 
 pub use crate::util::GetDir;
 
+pub use crate::util::iter::Iter;
+
 /// Run asynchronously with `async_std` feature.
 ///
 /// To use it, add the following code to the `Cargo.toml` file:
@@ -0,0 +1,15 @@
+use std::path::PathBuf;
+
+use crate::structs::target::Target;
+
+/// Enum to determine where the search upward stops.
+///
+/// The boundary directory itself is still searched, but none of its
+/// ancestors are.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Boundary {
+    /// Stop at the given directory.
+    Path(PathBuf),
+    /// Stop at a directory containing the target.
+    Target(Target),
+}
@@ -0,0 +1,63 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+};
+
+/// Cancel token struct.
+///
+/// It stops the searches it is given to from another thread or task. The
+/// clones of a token share its state.
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancelToken {
+    /// Create a new cancel token.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use get_dir::CancelToken;
+    ///
+    /// let token: CancelToken = CancelToken::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancel the searches using the token.
+    ///
+    /// They end with [`GetDirError::Cancelled`] before checking the next
+    /// directory.
+    ///
+    /// [`GetDirError::Cancelled`]: crate::GetDirError::Cancelled
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use get_dir::CancelToken;
+    ///
+    /// let token: CancelToken = CancelToken::new();
+    ///
+    /// token.cancel();
+    /// ```
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Check whether the token is cancelled.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use get_dir::CancelToken;
+    ///
+    /// let token: CancelToken = CancelToken::new();
+    ///
+    /// assert!(!token.is_cancelled());
+    /// ```
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
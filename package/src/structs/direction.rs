@@ -0,0 +1,8 @@
+/// Enum to describe where a match was found from the starting directory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// The match is the directory itself or one of its descendants.
+    Forward,
+    /// The match was reached by going up to an ancestor.
+    Reverse,
+}
@@ -0,0 +1,126 @@
+use std::{error::Error, fmt, io, path::PathBuf};
+
+/// Enum to describe why a search failed.
+///
+/// More variants may be added in the future.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum GetDirError {
+    /// No directory containing the targets was found.
+    NotFound {
+        /// The number of directories checked.
+        visited: usize,
+        /// The greatest distance from the directory the search started in.
+        depth: usize,
+        /// The number of directories that could not be read, and so were
+        /// not descended into.
+        ///
+        /// Unlike the directory the search started in, they do not fail the
+        /// search.
+        skipped: usize,
+    },
+    /// The directory the search started in could not be read.
+    PermissionDenied {
+        /// The directory that could not be read.
+        path: PathBuf,
+    },
+    /// A target, a boundary or a directory pattern is invalid.
+    InvalidTarget {
+        /// Why it is invalid.
+        reason: String,
+    },
+    /// The search was cancelled with a [`CancelToken`] before it finished.
+    ///
+    /// [`CancelToken`]: crate::CancelToken
+    Cancelled,
+    /// The search did not finish within its timeout.
+    Timeout,
+    /// An I/O error occurred on a path.
+    Io {
+        /// The path the error occurred on.
+        path: PathBuf,
+        /// The underlying error.
+        source: io::Error,
+    },
+}
+
+impl GetDirError {
+    pub(crate) fn invalid(error: io::Error) -> Self {
+        Self::InvalidTarget { reason: error.to_string() }
+    }
+
+    pub(crate) fn io(
+        path: PathBuf,
+        source: io::Error,
+    ) -> Self {
+        match source.kind() {
+            | io::ErrorKind::PermissionDenied => {
+                Self::PermissionDenied { path }
+            },
+            | _ => Self::Io { path, source },
+        }
+    }
+
+    /// Get the corresponding [`io::ErrorKind`].
+    pub fn kind(&self) -> io::ErrorKind {
+        match self {
+            | Self::NotFound { .. } => io::ErrorKind::NotFound,
+            | Self::PermissionDenied { .. } => io::ErrorKind::PermissionDenied,
+            | Self::InvalidTarget { .. } => io::ErrorKind::InvalidInput,
+            | Self::Cancelled => io::ErrorKind::Interrupted,
+            | Self::Timeout => io::ErrorKind::TimedOut,
+            | Self::Io { source, .. } => source.kind(),
+        }
+    }
+}
+
+impl fmt::Display for GetDirError {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            | Self::NotFound { visited, depth, skipped } => {
+                write!(
+                    f,
+                    "directory not found after visiting {visited} \
+                     directories up to depth {depth}"
+                )?;
+
+                match skipped {
+                    | 0 => Ok(()),
+                    | _ => write!(
+                        f,
+                        " and skipping {skipped} unreadable directories"
+                    ),
+                }
+            },
+            | Self::PermissionDenied { path } => {
+                write!(f, "permission denied: {}", path.display())
+            },
+            | Self::InvalidTarget { reason } => {
+                write!(f, "invalid target: {reason}")
+            },
+            | Self::Cancelled => write!(f, "search cancelled"),
+            | Self::Timeout => write!(f, "search timed out"),
+            | Self::Io { path, source } => {
+                write!(f, "{}: {source}", path.display())
+            },
+        }
+    }
+}
+
+impl Error for GetDirError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            | Self::Io { source, .. } => Some(source),
+            | _ => None,
+        }
+    }
+}
+
+impl From<GetDirError> for io::Error {
+    fn from(error: GetDirError) -> Self {
+        io::Error::new(error.kind(), error)
+    }
+}
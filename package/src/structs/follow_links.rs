@@ -0,0 +1,11 @@
+/// Enum to determine which symlinks to directories the forward search follows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FollowLinks {
+    /// Never follow symlinks, including the directory the search starts in.
+    Never,
+    /// Always follow symlinks.
+    #[default]
+    Always,
+    /// Follow only the directory the search starts in.
+    OnlyRoot,
+}
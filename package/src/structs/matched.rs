@@ -0,0 +1,23 @@
+use std::path::PathBuf;
+
+use crate::structs::direction::Direction;
+
+/// Match struct.
+///
+/// It describes a directory found by the search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Match {
+    /// The directory containing the targets.
+    pub dir: PathBuf,
+    /// The index of the first matched target.
+    pub target: usize,
+    /// The path of the entry matching the target.
+    ///
+    /// It is the directory itself for targets that do not match an entry,
+    /// such as custom targets.
+    pub path: PathBuf,
+    /// The distance from the directory the search started in.
+    pub depth: usize,
+    /// Whether the match was found going down or up.
+    pub direction: Direction,
+}
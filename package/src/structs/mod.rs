@@ -1 +1,17 @@
+pub mod boundary;
+
+pub mod cancel_token;
+
+pub mod direction;
+
+pub mod error;
+
+pub mod follow_links;
+
+pub mod matched;
+
+pub mod outcome;
+
+pub mod strategy;
+
 pub mod target;
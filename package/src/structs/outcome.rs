@@ -0,0 +1,14 @@
+use std::path::PathBuf;
+
+/// Enum to describe how a reverse search ended.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// A directory containing the targets was found.
+    Found(PathBuf),
+    /// The search stopped at a boundary without finding the targets.
+    Boundary(PathBuf),
+    /// The search reached the root without finding the targets.
+    Root,
+    /// The search ran out of depth without finding the targets.
+    Depth,
+}
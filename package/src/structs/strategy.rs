@@ -0,0 +1,22 @@
+/// Enum to determine the order of the forward search.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Strategy {
+    /// Breadth-first search.
+    ///
+    /// Shallower directories are always checked first, but the whole frontier
+    /// of the current level is kept in memory.
+    #[default]
+    Bfs,
+    /// Depth-first search in pre-order.
+    ///
+    /// Only the siblings along the current path are kept in memory, but a
+    /// deeper match may be found before a shallower one.
+    Dfs,
+    /// Iterative deepening search.
+    ///
+    /// It repeats depth-first searches with an increasing depth limit, so the
+    /// directories are checked level by level like [`Strategy::Bfs`] with the
+    /// memory use of [`Strategy::Dfs`], at the cost of reading the upper
+    /// levels again.
+    IterativeDeepening,
+}
@@ -0,0 +1,18 @@
+/// Enum to determine how the case of names is compared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Case {
+    /// Names are compared exactly.
+    #[default]
+    Sensitive,
+    /// ASCII letters are compared case-insensitively.
+    ///
+    /// Regular expressions use their case-insensitive flag instead.
+    AsciiInsensitive,
+    /// Names are compared after Unicode normalization (NFC) and full case
+    /// folding, so that NFC and NFD forms of the same name are equal, and so
+    /// are `ß` and `ss`.
+    ///
+    /// Regular expressions use their case-insensitive flag on the normalized
+    /// name instead, which folds one character at a time.
+    Insensitive,
+}
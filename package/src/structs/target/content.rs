@@ -0,0 +1,8 @@
+/// Enum to determine how the content of a file target is matched.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Content {
+    /// The content contains the substring.
+    Contains(String),
+    /// The content matches the regular expression.
+    Regex(String),
+}
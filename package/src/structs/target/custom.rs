@@ -0,0 +1,59 @@
+use std::{fmt, path::Path, sync::Arc};
+
+/// Custom target struct.
+///
+/// It wraps a predicate that is called with every candidate directory.
+#[derive(Clone)]
+pub struct CustomTarget {
+    predicate: Arc<dyn Fn(&Path) -> bool + Send + Sync>,
+}
+
+impl CustomTarget {
+    /// Create a new custom target.
+    ///
+    /// The predicate runs synchronously, including in the async versions.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use get_dir::CustomTarget;
+    ///
+    /// let target: CustomTarget = CustomTarget::new(|dir| {
+    ///     dir.join(".git").exists() && !dir.join(".gitmodules").exists()
+    /// });
+    /// ```
+    pub fn new<P>(predicate: P) -> Self
+    where
+        P: Fn(&Path) -> bool + Send + Sync + 'static,
+    {
+        Self { predicate: Arc::new(predicate) }
+    }
+
+    /// Check whether the directory matches the target.
+    pub fn is_match(
+        &self,
+        dir: &Path,
+    ) -> bool {
+        (self.predicate)(dir)
+    }
+}
+
+impl fmt::Debug for CustomTarget {
+    fn fmt(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        f.debug_struct("CustomTarget").finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for CustomTarget {
+    fn eq(
+        &self,
+        other: &Self,
+    ) -> bool {
+        Arc::ptr_eq(&self.predicate, &other.predicate)
+    }
+}
+
+impl Eq for CustomTarget {}
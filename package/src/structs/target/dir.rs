@@ -0,0 +1,118 @@
+use std::io;
+
+use crate::{
+    structs::target::{case::Case, syntax::Syntax},
+    util::matcher::check_relative,
+};
+
+/// Directory target struct.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DirTarget {
+    /// The name of the directory target.
+    ///
+    /// By default, it is a empty string.
+    pub name: String,
+    /// The syntax of the name.
+    ///
+    /// By default, it is [`Syntax::Literal`].
+    pub syntax: Syntax,
+    /// The case sensitivity of the name.
+    ///
+    /// By default, it is [`Case::Sensitive`].
+    pub case: Case,
+}
+
+impl DirTarget {
+    /// Create a new directory target.
+    ///
+    /// The name is a path relative to the searched directory, and it may
+    /// contain multiple components such as `.github/workflows`. Absolute paths
+    /// and `..` components are rejected when the search runs.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use get_dir::DirTarget;
+    ///
+    /// let target: DirTarget = DirTarget::new("src");
+    /// ```
+    pub fn new<N: Into<String>>(name: N) -> Self {
+        Self {
+            name: name.into(),
+            syntax: Syntax::Literal,
+            case: Case::Sensitive,
+        }
+    }
+
+    /// Create a new directory target from a relative path.
+    ///
+    /// Unlike [`DirTarget::new`], it fails immediately if the path is
+    /// absolute or contains `..` components.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use get_dir::DirTarget;
+    ///
+    /// let target: DirTarget = DirTarget::relative(".github/workflows").unwrap();
+    /// ```
+    pub fn relative<N: Into<String>>(name: N) -> io::Result<Self> {
+        let name: String = name.into();
+
+        check_relative(&name)?;
+
+        Ok(Self::new(name))
+    }
+
+    /// Create a new directory target matched by a glob pattern.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use get_dir::DirTarget;
+    ///
+    /// let target: DirTarget = DirTarget::glob("build-*");
+    /// ```
+    pub fn glob<P: Into<String>>(pattern: P) -> Self {
+        Self { syntax: Syntax::Glob, ..Self::new(pattern) }
+    }
+
+    /// Create a new directory target matched by a regular expression.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use get_dir::DirTarget;
+    ///
+    /// let target: DirTarget = DirTarget::regex(r"build-\d+");
+    /// ```
+    pub fn regex<P: Into<String>>(pattern: P) -> Self {
+        Self { syntax: Syntax::Regex, ..Self::new(pattern) }
+    }
+
+    /// Set the case sensitivity of the name.
+    ///
+    /// Unless it is [`Case::Sensitive`], the name is compared against the
+    /// entries of the searched directory.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use get_dir::{Case, DirTarget};
+    ///
+    /// let target: DirTarget = DirTarget::new("src").case(Case::Insensitive);
+    /// ```
+    pub fn case(
+        mut self,
+        case: Case,
+    ) -> Self {
+        self.case = case;
+        self
+    }
+}
+
+impl Default for DirTarget {
+    fn default() -> Self {
+        Self::new("")
+    }
+}
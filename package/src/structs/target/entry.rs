@@ -0,0 +1,155 @@
+use std::io;
+
+use crate::{
+    structs::target::{case::Case, syntax::Syntax},
+    util::matcher::check_relative,
+};
+
+/// Enum to determine the type of an entry target.
+///
+/// The type is checked without following symlinks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EntryKind {
+    /// Any entry regardless of its type.
+    #[default]
+    Any,
+    /// A symlink itself.
+    Symlink,
+    /// A Unix socket, never matches on non-Unix platforms.
+    Socket,
+    /// A FIFO, never matches on non-Unix platforms.
+    Fifo,
+    /// A block device, never matches on non-Unix platforms.
+    BlockDevice,
+    /// A character device, never matches on non-Unix platforms.
+    CharDevice,
+}
+
+/// Entry target struct.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntryTarget {
+    /// The name of the entry target.
+    ///
+    /// By default, it is a empty string.
+    pub name: String,
+    /// The syntax of the name.
+    ///
+    /// By default, it is [`Syntax::Literal`].
+    pub syntax: Syntax,
+    /// The case sensitivity of the name.
+    ///
+    /// By default, it is [`Case::Sensitive`].
+    pub case: Case,
+    /// The type of the entry.
+    ///
+    /// By default, it is [`EntryKind::Any`].
+    pub kind: EntryKind,
+}
+
+impl EntryTarget {
+    /// Create a new entry target of any type.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use get_dir::EntryTarget;
+    ///
+    /// let target: EntryTarget = EntryTarget::new("Makefile");
+    /// ```
+    pub fn new<N: Into<String>>(name: N) -> Self {
+        Self {
+            name: name.into(),
+            syntax: Syntax::Literal,
+            case: Case::Sensitive,
+            kind: EntryKind::Any,
+        }
+    }
+
+    /// Create a new entry target from a relative path.
+    ///
+    /// Unlike [`EntryTarget::new`], it fails immediately if the path is
+    /// absolute or contains `..` components.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use get_dir::EntryTarget;
+    ///
+    /// let target: EntryTarget = EntryTarget::relative("run/docker.sock").unwrap();
+    /// ```
+    pub fn relative<N: Into<String>>(name: N) -> io::Result<Self> {
+        let name: String = name.into();
+
+        check_relative(&name)?;
+
+        Ok(Self::new(name))
+    }
+
+    /// Create a new entry target matched by a glob pattern.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use get_dir::EntryTarget;
+    ///
+    /// let target: EntryTarget = EntryTarget::glob("*.sock");
+    /// ```
+    pub fn glob<P: Into<String>>(pattern: P) -> Self {
+        Self { syntax: Syntax::Glob, ..Self::new(pattern) }
+    }
+
+    /// Create a new entry target matched by a regular expression.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use get_dir::EntryTarget;
+    ///
+    /// let target: EntryTarget = EntryTarget::regex(r"^current$");
+    /// ```
+    pub fn regex<P: Into<String>>(pattern: P) -> Self {
+        Self { syntax: Syntax::Regex, ..Self::new(pattern) }
+    }
+
+    /// Set the case sensitivity of the name.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use get_dir::{Case, EntryTarget};
+    ///
+    /// let target: EntryTarget =
+    ///     EntryTarget::new("Makefile").case(Case::AsciiInsensitive);
+    /// ```
+    pub fn case(
+        mut self,
+        case: Case,
+    ) -> Self {
+        self.case = case;
+        self
+    }
+
+    /// Set the type of the entry.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use get_dir::{EntryKind, EntryTarget};
+    ///
+    /// let target: EntryTarget =
+    ///     EntryTarget::new("current").kind(EntryKind::Symlink);
+    /// ```
+    pub fn kind(
+        mut self,
+        kind: EntryKind,
+    ) -> Self {
+        self.kind = kind;
+        self
+    }
+}
+
+impl Default for EntryTarget {
+    fn default() -> Self {
+        Self::new("")
+    }
+}
@@ -0,0 +1,332 @@
+use std::{io, time::SystemTime};
+
+use crate::{
+    structs::target::{
+        case::Case, content::Content, metadata::MetadataConstraints,
+        structured::Structured, syntax::Syntax,
+    },
+    util::matcher::check_relative,
+};
+
+/// The default maximum number of bytes read for content and structured
+/// matching.
+pub const DEFAULT_READ_LIMIT: u64 = 1024 * 1024;
+
+/// File target struct.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileTarget {
+    /// The name of the file target.
+    ///
+    /// By default, it is a empty string.
+    pub name: String,
+    /// The syntax of the name.
+    ///
+    /// By default, it is [`Syntax::Literal`].
+    pub syntax: Syntax,
+    /// The case sensitivity of the name.
+    ///
+    /// By default, it is [`Case::Sensitive`].
+    pub case: Case,
+    /// The content that the file must match.
+    ///
+    /// By default, it is [`None`].
+    pub content: Option<Content>,
+    /// The key that the structured file must contain.
+    ///
+    /// Files that cannot be parsed do not match.
+    ///
+    /// By default, it is [`None`].
+    pub structured: Option<Structured>,
+    /// The metadata constraints of the file.
+    ///
+    /// By default, there is no constraint.
+    pub metadata: MetadataConstraints,
+    /// The maximum number of bytes read for content and structured matching.
+    ///
+    /// By default, it is [`DEFAULT_READ_LIMIT`].
+    pub read_limit: u64,
+}
+
+impl FileTarget {
+    /// Create a new file target.
+    ///
+    /// The name is a path relative to the searched directory, and it may
+    /// contain multiple components such as `.git/HEAD`. Absolute paths
+    /// and `..` components are rejected when the search runs.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use get_dir::FileTarget;
+    ///
+    /// let target: FileTarget = FileTarget::new("Cargo.toml");
+    /// ```
+    pub fn new<N: Into<String>>(name: N) -> Self {
+        Self {
+            name: name.into(),
+            syntax: Syntax::Literal,
+            case: Case::Sensitive,
+            content: None,
+            structured: None,
+            metadata: MetadataConstraints::default(),
+            read_limit: DEFAULT_READ_LIMIT,
+        }
+    }
+
+    /// Create a new file target from a relative path.
+    ///
+    /// Unlike [`FileTarget::new`], it fails immediately if the path is
+    /// absolute or contains `..` components.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use get_dir::FileTarget;
+    ///
+    /// let target: FileTarget = FileTarget::relative(".git/HEAD").unwrap();
+    /// ```
+    pub fn relative<N: Into<String>>(name: N) -> io::Result<Self> {
+        let name: String = name.into();
+
+        check_relative(&name)?;
+
+        Ok(Self::new(name))
+    }
+
+    /// Create a new file target matched by a glob pattern.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use get_dir::FileTarget;
+    ///
+    /// let target: FileTarget = FileTarget::glob("*.sln");
+    /// ```
+    pub fn glob<P: Into<String>>(pattern: P) -> Self {
+        Self { syntax: Syntax::Glob, ..Self::new(pattern) }
+    }
+
+    /// Create a new file target matched by a regular expression.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use get_dir::FileTarget;
+    ///
+    /// let target: FileTarget = FileTarget::regex(r"^Dockerfile(\..+)?$");
+    /// ```
+    pub fn regex<P: Into<String>>(pattern: P) -> Self {
+        Self { syntax: Syntax::Regex, ..Self::new(pattern) }
+    }
+
+    /// Set the case sensitivity of the name.
+    ///
+    /// Unless it is [`Case::Sensitive`], the name is compared against the
+    /// entries of the searched directory.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use get_dir::{Case, FileTarget};
+    ///
+    /// let target: FileTarget = FileTarget::new("README.md").case(Case::Insensitive);
+    /// ```
+    pub fn case(
+        mut self,
+        case: Case,
+    ) -> Self {
+        self.case = case;
+        self
+    }
+
+    /// Match only when the file content contains the substring.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use get_dir::FileTarget;
+    ///
+    /// let target: FileTarget =
+    ///     FileTarget::new("Cargo.toml").contains("[workspace]");
+    /// ```
+    pub fn contains<S: Into<String>>(
+        mut self,
+        substring: S,
+    ) -> Self {
+        self.content = Some(Content::Contains(substring.into()));
+        self
+    }
+
+    /// Match only when the file content matches the regular expression.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use get_dir::FileTarget;
+    ///
+    /// let target: FileTarget =
+    ///     FileTarget::new("Cargo.toml").content_regex(r"(?m)^\[workspace\]$");
+    /// ```
+    pub fn content_regex<P: Into<String>>(
+        mut self,
+        pattern: P,
+    ) -> Self {
+        self.content = Some(Content::Regex(pattern.into()));
+        self
+    }
+
+    /// Match only when the structured file contains the key.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use get_dir::{FileTarget, Format, Structured};
+    ///
+    /// let target: FileTarget = FileTarget::new("package.json")
+    ///     .structured(Structured::new(Format::Json, "workspaces"));
+    /// ```
+    pub fn structured(
+        mut self,
+        structured: Structured,
+    ) -> Self {
+        self.structured = Some(structured);
+        self
+    }
+
+    /// Match only when the file size is at least the given bytes.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use get_dir::FileTarget;
+    ///
+    /// let target: FileTarget = FileTarget::new(".env").min_size(1);
+    /// ```
+    pub fn min_size(
+        mut self,
+        size: u64,
+    ) -> Self {
+        self.metadata.min_size = Some(size);
+        self
+    }
+
+    /// Match only when the file size is at most the given bytes.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use get_dir::FileTarget;
+    ///
+    /// let target: FileTarget = FileTarget::new("Cargo.toml").max_size(4096);
+    /// ```
+    pub fn max_size(
+        mut self,
+        size: u64,
+    ) -> Self {
+        self.metadata.max_size = Some(size);
+        self
+    }
+
+    /// Match only when the file is modified after the given time.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::time::{Duration, SystemTime};
+    ///
+    /// use get_dir::FileTarget;
+    ///
+    /// let target: FileTarget = FileTarget::new("lockfile")
+    ///     .modified_after(SystemTime::now() - Duration::from_secs(3600));
+    /// ```
+    pub fn modified_after(
+        mut self,
+        time: SystemTime,
+    ) -> Self {
+        self.metadata.modified_after = Some(time);
+        self
+    }
+
+    /// Match only when the file is modified before the given time.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::time::SystemTime;
+    ///
+    /// use get_dir::FileTarget;
+    ///
+    /// let target: FileTarget =
+    ///     FileTarget::new("lockfile").modified_before(SystemTime::now());
+    /// ```
+    pub fn modified_before(
+        mut self,
+        time: SystemTime,
+    ) -> Self {
+        self.metadata.modified_before = Some(time);
+        self
+    }
+
+    /// Match only when all of the given Unix mode bits are set.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use get_dir::FileTarget;
+    ///
+    /// let target: FileTarget = FileTarget::new("id_rsa").mode(0o600);
+    /// ```
+    pub fn mode(
+        mut self,
+        mode: u32,
+    ) -> Self {
+        self.metadata.mode = Some(mode);
+        self
+    }
+
+    /// Match only when the file is executable or not.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use get_dir::FileTarget;
+    ///
+    /// let target: FileTarget = FileTarget::new("gradlew").executable(true);
+    /// ```
+    pub fn executable(
+        mut self,
+        executable: bool,
+    ) -> Self {
+        self.metadata.executable = Some(executable);
+        self
+    }
+
+    /// Set the maximum number of bytes read for content and structured
+    /// matching.
+    ///
+    /// Content beyond the limit is ignored, and a truncated structured file
+    /// usually fails to parse.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use get_dir::FileTarget;
+    ///
+    /// let target: FileTarget = FileTarget::new("Cargo.toml")
+    ///     .contains("[workspace]")
+    ///     .read_limit(64 * 1024);
+    /// ```
+    pub fn read_limit(
+        mut self,
+        limit: u64,
+    ) -> Self {
+        self.read_limit = limit;
+        self
+    }
+}
+
+impl Default for FileTarget {
+    fn default() -> Self {
+        Self::new("")
+    }
+}
@@ -0,0 +1,36 @@
+use std::time::SystemTime;
+
+/// Metadata constraints struct.
+///
+/// Every constraint that is set must be satisfied.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MetadataConstraints {
+    /// The minimum size in bytes.
+    ///
+    /// By default, it is [`None`].
+    pub min_size: Option<u64>,
+    /// The maximum size in bytes.
+    ///
+    /// By default, it is [`None`].
+    pub max_size: Option<u64>,
+    /// The time the file must be modified after.
+    ///
+    /// By default, it is [`None`].
+    pub modified_after: Option<SystemTime>,
+    /// The time the file must be modified before.
+    ///
+    /// By default, it is [`None`].
+    pub modified_before: Option<SystemTime>,
+    /// The Unix mode bits that must all be set.
+    ///
+    /// It never matches on non-Unix platforms.
+    ///
+    /// By default, it is [`None`].
+    pub mode: Option<u32>,
+    /// Whether any of the Unix executable bits must be set or not.
+    ///
+    /// It never matches on non-Unix platforms.
+    ///
+    /// By default, it is [`None`].
+    pub executable: Option<bool>,
+}
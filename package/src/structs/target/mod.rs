@@ -0,0 +1,43 @@
+pub mod case;
+
+pub mod content;
+
+pub mod custom;
+
+pub mod dir;
+
+pub mod entry;
+
+pub mod file;
+
+pub mod metadata;
+
+pub mod structured;
+
+pub mod syntax;
+
+use crate::structs::target::{
+    custom::CustomTarget, dir::DirTarget, entry::EntryTarget, file::FileTarget,
+};
+
+/// Enum to determine what the target is.
+///
+/// The combinators [`Target::All`], [`Target::Any`] and [`Target::Not`] can
+/// be nested arbitrarily.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Target {
+    /// The target is a directory.
+    Dir(DirTarget),
+    /// The target is a file.
+    File(FileTarget),
+    /// The target is an entry of any type, such as a symlink or a socket.
+    Entry(EntryTarget),
+    /// The target is a custom predicate on the directory.
+    Custom(CustomTarget),
+    /// The target matches when all of the targets match.
+    All(Vec<Target>),
+    /// The target matches when any of the targets matches.
+    Any(Vec<Target>),
+    /// The target matches when the target does not match.
+    Not(Box<Target>),
+}
@@ -0,0 +1,68 @@
+/// Enum to determine the format of a structured file.
+///
+/// Each format requires its own feature, and a target using a format
+/// whose feature is disabled is rejected as invalid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// TOML format, requires `toml` feature.
+    Toml,
+    /// JSON format, requires `json` feature.
+    Json,
+    /// YAML format, requires `yaml` feature.
+    Yaml,
+}
+
+/// Structured key struct.
+///
+/// It checks a key in a structured file, optionally with an expected value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Structured {
+    /// The format of the file.
+    pub format: Format,
+    /// The dotted path of the key, such as `tool.poetry`.
+    ///
+    /// Numeric segments index into arrays.
+    pub key: String,
+    /// The expected value of the key.
+    ///
+    /// Scalar values are compared by their textual representation.
+    ///
+    /// By default, it is [`None`], which matches any value.
+    pub value: Option<String>,
+}
+
+impl Structured {
+    /// Create a new structured key.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use get_dir::{Format, Structured};
+    ///
+    /// let structured: Structured = Structured::new(Format::Json, "workspaces");
+    /// ```
+    pub fn new<K: Into<String>>(
+        format: Format,
+        key: K,
+    ) -> Self {
+        Self { format, key: key.into(), value: None }
+    }
+
+    /// Set the expected value of the key.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use get_dir::{Format, Structured};
+    ///
+    /// let structured: Structured =
+    ///     Structured::new(Format::Toml, "package.name").value("get_dir");
+    /// ```
+    pub fn value<V: Into<String>>(
+        mut self,
+        value: V,
+    ) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+}
@@ -0,0 +1,16 @@
+/// Enum to determine how the name of a target is interpreted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Syntax {
+    /// The name is a literal path relative to the searched directory.
+    #[default]
+    Literal,
+    /// The name is a glob pattern matched against the entries of the
+    /// searched directory.
+    ///
+    /// It supports `*`, `?`, `[...]` and `**` for any number of
+    /// directories, with `/` separating path components.
+    Glob,
+    /// The name is a regular expression matched against the names of the
+    /// entries of the searched directory.
+    Regex,
+}
@@ -0,0 +1,187 @@
+use std::{
+    ffi::OsString,
+    fs,
+    future::{self, Future},
+    io::{self, Read},
+    path::{Path, PathBuf},
+    pin::pin,
+    task::{Context, Poll, Waker},
+};
+
+/// An entry of a directory listing.
+#[derive(Debug, Clone)]
+pub(crate) struct Entry {
+    pub(crate) name: OsString,
+    pub(crate) path: PathBuf,
+    /// The type of the entry itself, without following symlinks.
+    pub(crate) file_type: fs::FileType,
+}
+
+/// Filesystem operations used by the search.
+///
+/// Each runtime provides its own implementation, so that the same search
+/// logic runs on top of the runtime's own filesystem calls.
+pub(crate) trait Fs: Sync {
+    fn read_dir(
+        &self,
+        path: &Path,
+    ) -> impl Future<Output = io::Result<Vec<Entry>>> + Send;
+
+    fn metadata(
+        &self,
+        path: &Path,
+    ) -> impl Future<Output = io::Result<fs::Metadata>> + Send;
+
+    fn symlink_metadata(
+        &self,
+        path: &Path,
+    ) -> impl Future<Output = io::Result<fs::Metadata>> + Send;
+
+    fn canonicalize(
+        &self,
+        path: &Path,
+    ) -> impl Future<Output = io::Result<PathBuf>> + Send;
+
+    /// Read at most `limit` bytes from the start of the file.
+    fn read(
+        &self,
+        path: &Path,
+        limit: u64,
+    ) -> impl Future<Output = io::Result<Vec<u8>>> + Send;
+}
+
+/// Filesystem operations backed by [`std::fs`].
+///
+/// Every future it returns is ready on the first poll.
+pub(crate) struct SyncFs;
+
+impl Fs for SyncFs {
+    fn read_dir(
+        &self,
+        path: &Path,
+    ) -> impl Future<Output = io::Result<Vec<Entry>>> + Send {
+        future::ready(fs::read_dir(path).map(|entries| {
+            entries
+                .flatten()
+                .filter_map(|entry| {
+                    Some(Entry {
+                        name: entry.file_name(),
+                        path: entry.path(),
+                        file_type: entry.file_type().ok()?,
+                    })
+                })
+                .collect()
+        }))
+    }
+
+    fn metadata(
+        &self,
+        path: &Path,
+    ) -> impl Future<Output = io::Result<fs::Metadata>> + Send {
+        future::ready(fs::metadata(path))
+    }
+
+    fn symlink_metadata(
+        &self,
+        path: &Path,
+    ) -> impl Future<Output = io::Result<fs::Metadata>> + Send {
+        future::ready(fs::symlink_metadata(path))
+    }
+
+    fn canonicalize(
+        &self,
+        path: &Path,
+    ) -> impl Future<Output = io::Result<PathBuf>> + Send {
+        future::ready(fs::canonicalize(path))
+    }
+
+    fn read(
+        &self,
+        path: &Path,
+        limit: u64,
+    ) -> impl Future<Output = io::Result<Vec<u8>>> + Send {
+        future::ready(read_sync(path, limit))
+    }
+}
+
+fn read_sync(
+    path: &Path,
+    limit: u64,
+) -> io::Result<Vec<u8>> {
+    let mut buffer: Vec<u8> = Vec::new();
+
+    fs::File::open(path)?.take(limit).read_to_end(&mut buffer)?;
+
+    Ok(buffer)
+}
+
+/// Check whether the entry is a directory, following symlinks.
+pub(crate) async fn is_dir_entry<F: Fs>(
+    fs: &F,
+    entry: &Entry,
+) -> bool {
+    if entry.file_type.is_symlink() {
+        return fs.metadata(&entry.path).await.is_ok_and(|m| m.is_dir());
+    }
+
+    entry.file_type.is_dir()
+}
+
+/// Identity of a directory, used to detect cycles.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct Identity(#[cfg(unix)] (u64, u64), #[cfg(not(unix))] PathBuf);
+
+/// Get the identity of the path, following symlinks.
+///
+/// It is the device and inode pair on Unix platforms, and the canonical path
+/// on other platforms.
+pub(crate) async fn identity<F: Fs>(
+    fs: &F,
+    path: &Path,
+) -> Option<Identity> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt as _;
+
+        let metadata: fs::Metadata = fs.metadata(path).await.ok()?;
+
+        Some(Identity((metadata.dev(), metadata.ino())))
+    }
+
+    #[cfg(not(unix))]
+    {
+        fs.canonicalize(path).await.ok().map(Identity)
+    }
+}
+
+/// Get the device of the path, following symlinks.
+///
+/// It is always [`None`] on non-Unix platforms.
+pub(crate) async fn device<F: Fs>(
+    fs: &F,
+    path: &Path,
+) -> Option<u64> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt as _;
+
+        fs.metadata(path).await.ok().map(|metadata| metadata.dev())
+    }
+
+    #[cfg(not(unix))]
+    {
+        let _ = (fs, path);
+
+        None
+    }
+}
+
+/// Run a future driven by [`SyncFs`] to completion.
+pub(crate) fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+
+    match future.as_mut().poll(&mut Context::from_waker(Waker::noop())) {
+        | Poll::Ready(output) => output,
+        | Poll::Pending => unreachable!("synchronous filesystem never pends"),
+    }
+}
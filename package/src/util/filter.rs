@@ -0,0 +1,131 @@
+use std::{borrow::Cow, io, path::Path};
+
+use crate::util::matcher::check_relative;
+
+/// A compiled directory pattern, where [`None`] stands for `**`.
+type Rule = Vec<Option<glob::Pattern>>;
+
+/// Exclude and include filters of the forward search.
+pub(crate) struct Filter {
+    exclude: Vec<Rule>,
+    include: Vec<Rule>,
+}
+
+fn compile_rule(pattern: &str) -> io::Result<Rule> {
+    check_relative(pattern)?;
+
+    let mut rule: Rule = Vec::new();
+
+    // A pattern without a separator matches a name at any depth.
+    if !pattern.trim_end_matches('/').contains('/') {
+        rule.push(None);
+    }
+
+    for part in pattern.split('/').filter(|p| !p.is_empty() && *p != ".") {
+        if part == "**" {
+            rule.push(None);
+            continue;
+        }
+
+        rule.push(Some(glob::Pattern::new(part).map_err(|error| {
+            io::Error::new(io::ErrorKind::InvalidInput, error)
+        })?));
+    }
+
+    if rule.iter().all(Option::is_none) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("empty directory pattern: {pattern:?}"),
+        ));
+    }
+
+    Ok(rule)
+}
+
+/// Check whether the rule matches the names.
+fn is_rule_match(
+    rule: &[Option<glob::Pattern>],
+    names: &[&str],
+) -> bool {
+    match rule.split_first() {
+        | None => names.is_empty(),
+        | Some((None, rest)) => {
+            (0..=names.len()).any(|i| is_rule_match(rest, &names[i..]))
+        },
+        | Some((Some(pattern), rest)) => match names.split_first() {
+            | Some((name, names)) => {
+                pattern.matches(name) && is_rule_match(rest, names)
+            },
+            | None => false,
+        },
+    }
+}
+
+/// Check whether the rule may match a descendant of the names.
+fn is_rule_prefix(
+    rule: &[Option<glob::Pattern>],
+    names: &[&str],
+) -> bool {
+    match rule.split_first() {
+        | None => false,
+        | Some((None, _)) => true,
+        | Some((Some(pattern), rest)) => match names.split_first() {
+            | Some((name, names)) => {
+                pattern.matches(name) && is_rule_prefix(rest, names)
+            },
+            | None => true,
+        },
+    }
+}
+
+impl Filter {
+    pub(crate) fn new(
+        exclude: &[String],
+        include: &[String],
+    ) -> io::Result<Self> {
+        Ok(Self {
+            exclude: exclude
+                .iter()
+                .map(|p| compile_rule(p))
+                .collect::<io::Result<_>>()?,
+            include: include
+                .iter()
+                .map(|p| compile_rule(p))
+                .collect::<io::Result<_>>()?,
+        })
+    }
+
+    fn names(path: &Path) -> Vec<Cow<'_, str>> {
+        path.iter().map(|name| name.to_string_lossy()).collect()
+    }
+
+    /// Check whether the directory, relative to the root, is searched.
+    pub(crate) fn is_included(
+        &self,
+        path: &Path,
+    ) -> bool {
+        let names: Vec<Cow<'_, str>> = Self::names(path);
+        let names: Vec<&str> = names.iter().map(AsRef::as_ref).collect();
+
+        self.include.is_empty()
+            || self.include.iter().any(|rule| is_rule_match(rule, &names))
+    }
+
+    /// Check whether the directory, relative to the root, is descended into.
+    pub(crate) fn is_traversed(
+        &self,
+        path: &Path,
+    ) -> bool {
+        let names: Vec<Cow<'_, str>> = Self::names(path);
+        let names: Vec<&str> = names.iter().map(AsRef::as_ref).collect();
+
+        if self.exclude.iter().any(|rule| is_rule_match(rule, &names)) {
+            return false;
+        }
+
+        self.include.is_empty()
+            || self.include.iter().any(|rule| {
+                is_rule_match(rule, &names) || is_rule_prefix(rule, &names)
+            })
+    }
+}
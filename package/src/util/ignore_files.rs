@@ -0,0 +1,211 @@
+use std::{
+    env,
+    path::{Path, PathBuf},
+    sync::{Arc, OnceLock},
+};
+
+use ignore::{
+    Match,
+    gitignore::{Gitignore, GitignoreBuilder},
+};
+
+use crate::util::file_system::Fs;
+
+/// The per-directory ignore files, from lowest to highest precedence.
+const IGNORE_FILES: [&str; 2] = [".gitignore", ".ignore"];
+
+fn env_path(name: &str) -> Option<PathBuf> {
+    env::var_os(name).filter(|value| !value.is_empty()).map(PathBuf::from)
+}
+
+fn home_dir() -> Option<PathBuf> {
+    #[allow(deprecated)]
+    env::home_dir()
+}
+
+/// Get the `core.excludesFile` setting from the content of a git config.
+fn parse_excludes_file(bytes: &[u8]) -> Option<PathBuf> {
+    static REGEX: OnceLock<regex::bytes::Regex> = OnceLock::new();
+
+    let regex: &regex::bytes::Regex = REGEX.get_or_init(|| {
+        regex::bytes::Regex::new(
+            r#"(?im-u)^\s*excludesfile\s*=\s*"?\s*(\S+?)\s*"?\s*$"#,
+        )
+        .unwrap()
+    });
+
+    let path: &str =
+        std::str::from_utf8(regex.captures(bytes)?.get(1)?.as_bytes()).ok()?;
+
+    Some(PathBuf::from(match home_dir() {
+        | Some(home) => path.replace('~', &home.to_string_lossy()),
+        | None => path.to_string(),
+    }))
+}
+
+/// The global excludes file of git, resolved once per search.
+///
+/// It is looked up like git does, but the config files are read through the
+/// file system of the search, so an async search never blocks on them.
+#[derive(Default)]
+pub(crate) struct Excludes {
+    path: Option<Option<PathBuf>>,
+}
+
+impl Excludes {
+    async fn resolve<F: Fs>(fs: &F) -> Option<PathBuf> {
+        let home: Option<PathBuf> = home_dir();
+        let config: Option<PathBuf> = env_path("XDG_CONFIG_HOME")
+            .or_else(|| home.as_ref().map(|home| home.join(".config")));
+
+        let candidates: [Option<PathBuf>; 4] = [
+            env_path("GIT_CONFIG_GLOBAL"),
+            home.map(|home| home.join(".gitconfig")),
+            config.as_ref().map(|config| config.join("git").join("config")),
+            Some(
+                env_path("GIT_CONFIG_SYSTEM")
+                    .unwrap_or_else(|| PathBuf::from("/etc/gitconfig")),
+            ),
+        ];
+
+        for path in candidates.into_iter().flatten() {
+            let Ok(bytes) = fs.read(&path, u64::MAX).await else {
+                continue;
+            };
+
+            if let Some(path) = parse_excludes_file(&bytes) {
+                return Some(path);
+            }
+        }
+
+        config.map(|config| config.join("git").join("ignore"))
+    }
+
+    async fn path<F: Fs>(
+        &mut self,
+        fs: &F,
+    ) -> Option<&Path> {
+        if self.path.is_none() {
+            self.path = Some(Self::resolve(fs).await);
+        }
+
+        self.path.as_ref().and_then(Option::as_deref)
+    }
+}
+
+/// Ignore rules of a directory, chained to the rules of its ancestors.
+pub(crate) struct IgnoreFiles {
+    matcher: Gitignore,
+    parent: Option<Arc<IgnoreFiles>>,
+}
+
+impl IgnoreFiles {
+    /// Get the ignore rules that apply to the directory the search starts in.
+    ///
+    /// Inside a repository, they are the global excludes file, the
+    /// `.git/info/exclude` file of the repository, and the ignore files of
+    /// the ancestors inside the repository, but not of the directory itself.
+    /// Outside a repository, there are none.
+    pub(crate) async fn root<F: Fs>(
+        fs: &F,
+        dir: &Path,
+        excludes: &mut Excludes,
+    ) -> Option<Arc<Self>> {
+        let mut repository: Option<&Path> = None;
+
+        for ancestor in dir.ancestors() {
+            if fs.symlink_metadata(&ancestor.join(".git")).await.is_ok() {
+                repository = Some(ancestor);
+                break;
+            }
+        }
+
+        let repository: &Path = repository?;
+
+        let mut ignore: Option<Arc<Self>> = match excludes.path(fs).await {
+            | Some(path) => {
+                Self::load(fs, repository, &[path.to_path_buf()], None).await
+            },
+            | None => None,
+        };
+
+        let mut ancestors: Vec<&Path> = dir
+            .ancestors()
+            .skip(1)
+            .take_while(|ancestor| ancestor.starts_with(repository))
+            .collect();
+
+        ancestors.reverse();
+
+        for ancestor in ancestors {
+            ignore = Self::child(fs, ignore, ancestor).await;
+        }
+
+        ignore
+    }
+
+    /// Get the ignore rules that apply to the entries of the directory.
+    pub(crate) async fn child<F: Fs>(
+        fs: &F,
+        parent: Option<Arc<Self>>,
+        dir: &Path,
+    ) -> Option<Arc<Self>> {
+        let mut paths: Vec<PathBuf> =
+            vec![dir.join(".git").join("info").join("exclude")];
+
+        paths.extend(IGNORE_FILES.iter().map(|name| dir.join(name)));
+
+        Self::load(fs, dir, &paths, parent.clone()).await.or(parent)
+    }
+
+    async fn load<F: Fs>(
+        fs: &F,
+        dir: &Path,
+        paths: &[PathBuf],
+        parent: Option<Arc<Self>>,
+    ) -> Option<Arc<Self>> {
+        let mut builder: GitignoreBuilder = GitignoreBuilder::new(dir);
+        let mut found: bool = false;
+
+        for path in paths {
+            let Ok(bytes) = fs.read(path, u64::MAX).await else {
+                continue;
+            };
+
+            found = true;
+
+            for line in String::from_utf8_lossy(&bytes).lines() {
+                // Invalid lines are skipped, as git does.
+                let _ = builder.add_line(Some(path.clone()), line);
+            }
+        }
+
+        if !found {
+            return None;
+        }
+
+        let matcher: Gitignore = builder.build().ok()?;
+
+        Some(Arc::new(Self { matcher, parent }))
+    }
+
+    /// Check whether the directory is ignored.
+    ///
+    /// The rules of the nearest directory take precedence.
+    pub(crate) fn is_ignored(
+        &self,
+        dir: &Path,
+    ) -> bool {
+        let mut current: Option<&Self> = Some(self);
+
+        while let Some(ignore) = current {
+            match ignore.matcher.matched(dir, true) {
+                | Match::Ignore(_) => return true,
+                | Match::Whitelist(_) => return false,
+                | Match::None => current = ignore.parent.as_deref(),
+            }
+        }
+
+        false
+    }
+}
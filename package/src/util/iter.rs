@@ -0,0 +1,35 @@
+use std::iter::FusedIterator;
+
+use crate::{
+    structs::{error::GetDirError, matched::Match},
+    util::{
+        file_system::{SyncFs, block_on},
+        walker::Search,
+    },
+};
+
+/// Iterator over the directories containing any of the targets.
+///
+/// It is created by [`GetDir::iter`] or [`GetDir::iter_reverse`].
+pub struct Iter {
+    search: Result<Search<SyncFs>, Option<GetDirError>>,
+}
+
+impl Iter {
+    pub(crate) fn new(search: Result<Search<SyncFs>, GetDirError>) -> Self {
+        Self { search: search.map_err(Some) }
+    }
+}
+
+impl Iterator for Iter {
+    type Item = Result<Match, GetDirError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.search {
+            | Ok(search) => block_on(search.next()),
+            | Err(error) => error.take().map(Err),
+        }
+    }
+}
+
+impl FusedIterator for Iter {}
@@ -0,0 +1,703 @@
+use std::{
+    borrow::Cow,
+    fs,
+    future::Future,
+    io,
+    path::{self, Path, PathBuf},
+    pin::Pin,
+};
+
+use caseless::Caseless as _;
+use unicode_normalization::UnicodeNormalization as _;
+
+use crate::{
+    structs::target::{
+        Target,
+        case::Case,
+        content::Content,
+        custom::CustomTarget,
+        entry::EntryKind,
+        file::FileTarget,
+        metadata::MetadataConstraints,
+        structured::{Format, Structured},
+        syntax::Syntax,
+    },
+    util::{
+        file_system::{Entry, Fs},
+        structured::is_structured_match,
+    },
+};
+
+type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+#[derive(Debug, Clone, Copy)]
+enum Kind {
+    Dir,
+    File,
+    Entry(EntryKind),
+}
+
+#[derive(Debug)]
+pub(crate) enum Component {
+    Exact(String),
+    Pattern(glob::Pattern),
+    Recursive,
+}
+
+#[derive(Debug)]
+pub(crate) enum Name {
+    Literal(PathBuf),
+    Components(Vec<Component>, Case),
+    Regex(regex::Regex, Case),
+}
+
+#[derive(Debug)]
+pub(crate) struct FileMatcher {
+    name: Name,
+    content: Option<regex::bytes::Regex>,
+    structured: Option<Structured>,
+    metadata: Option<MetadataConstraints>,
+    read_limit: u64,
+}
+
+/// A target prepared for matching.
+#[derive(Debug)]
+pub(crate) enum Matcher {
+    Dir(Name),
+    File(FileMatcher),
+    Entry(Name, EntryKind),
+    Custom(CustomTarget),
+    All(Vec<Matcher>),
+    Any(Vec<Matcher>),
+    Not(Box<Matcher>),
+}
+
+fn invalid_input<E>(error: E) -> io::Error
+where
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    io::Error::new(io::ErrorKind::InvalidInput, error)
+}
+
+/// Check that the name stays inside the searched directory.
+pub(crate) fn check_relative(name: &str) -> io::Result<()> {
+    for component in Path::new(name).components() {
+        match component {
+            | path::Component::Prefix(_) | path::Component::RootDir => {
+                return Err(invalid_input(format!(
+                    "target must be a relative path: {name:?}"
+                )));
+            },
+            | path::Component::ParentDir => {
+                return Err(invalid_input(format!(
+                    "target must not contain `..`: {name:?}"
+                )));
+            },
+            | path::Component::CurDir | path::Component::Normal(_) => {},
+        }
+    }
+
+    Ok(())
+}
+
+fn fold(
+    name: &str,
+    case: Case,
+) -> Cow<'_, str> {
+    match case {
+        | Case::Sensitive => Cow::Borrowed(name),
+        | Case::AsciiInsensitive => Cow::Owned(name.to_ascii_lowercase()),
+        | Case::Insensitive => {
+            Cow::Owned(name.nfd().default_case_fold().nfc().collect())
+        },
+    }
+}
+
+fn compile_glob(
+    pattern: &str,
+    case: Case,
+) -> io::Result<Vec<Component>> {
+    check_relative(pattern)?;
+
+    let pattern: Cow<'_, str> = fold(pattern, case);
+
+    let mut components: Vec<Component> = Vec::new();
+
+    for part in pattern.split('/').filter(|p| !p.is_empty() && *p != ".") {
+        if part == "**" {
+            if !matches!(components.last(), Some(Component::Recursive)) {
+                components.push(Component::Recursive);
+            }
+
+            continue;
+        }
+
+        let pattern: glob::Pattern =
+            glob::Pattern::new(part).map_err(invalid_input)?;
+
+        components.push(Component::Pattern(pattern));
+    }
+
+    match components.last() {
+        | None => {
+            return Err(invalid_input(format!(
+                "empty glob pattern: {pattern:?}"
+            )));
+        },
+        | Some(Component::Recursive) => {
+            components.push(Component::Pattern(
+                glob::Pattern::new("*").map_err(invalid_input)?,
+            ));
+        },
+        | Some(Component::Exact(_) | Component::Pattern(_)) => {},
+    }
+
+    Ok(components)
+}
+
+fn compile_name(
+    name: &str,
+    syntax: Syntax,
+    case: Case,
+) -> io::Result<Name> {
+    match syntax {
+        | Syntax::Literal => {
+            check_relative(name)?;
+
+            if case == Case::Sensitive {
+                return Ok(Name::Literal(PathBuf::from(name)));
+            }
+
+            let components: Vec<Component> = name
+                .split('/')
+                .filter(|p| !p.is_empty() && *p != ".")
+                .map(|p| Component::Exact(fold(p, case).into_owned()))
+                .collect();
+
+            if components.is_empty() {
+                return Ok(Name::Literal(PathBuf::new()));
+            }
+
+            Ok(Name::Components(components, case))
+        },
+        | Syntax::Glob => {
+            compile_glob(name, case).map(|c| Name::Components(c, case))
+        },
+        | Syntax::Regex => regex::RegexBuilder::new(&match case {
+            | Case::Insensitive => name.nfc().collect(),
+            | Case::Sensitive | Case::AsciiInsensitive => name.to_string(),
+        })
+        .case_insensitive(case != Case::Sensitive)
+        .build()
+        .map(|r| Name::Regex(r, case))
+        .map_err(invalid_input),
+    }
+}
+
+fn compile_content(content: &Content) -> io::Result<regex::bytes::Regex> {
+    let pattern: String = match content {
+        | Content::Contains(substring) => regex::escape(substring),
+        | Content::Regex(pattern) => pattern.clone(),
+    };
+
+    regex::bytes::Regex::new(&pattern).map_err(invalid_input)
+}
+
+fn compile_structured(structured: &Structured) -> io::Result<Structured> {
+    let (enabled, feature): (bool, &str) = match structured.format {
+        | Format::Toml => (cfg!(feature = "toml"), "toml"),
+        | Format::Json => (cfg!(feature = "json"), "json"),
+        | Format::Yaml => (cfg!(feature = "yaml"), "yaml"),
+    };
+
+    if !enabled {
+        return Err(invalid_input(format!(
+            "structured key `{}` requires `{feature}` feature",
+            structured.key
+        )));
+    }
+
+    Ok(structured.clone())
+}
+
+fn compile_file(target: &FileTarget) -> io::Result<FileMatcher> {
+    Ok(FileMatcher {
+        name: compile_name(&target.name, target.syntax, target.case)?,
+        content: match &target.content {
+            | Some(content) => Some(compile_content(content)?),
+            | None => None,
+        },
+        structured: match &target.structured {
+            | Some(structured) => Some(compile_structured(structured)?),
+            | None => None,
+        },
+        metadata: if target.metadata == MetadataConstraints::default() {
+            None
+        } else {
+            Some(target.metadata.clone())
+        },
+        read_limit: target.read_limit,
+    })
+}
+
+fn compile_target(target: &Target) -> io::Result<Matcher> {
+    match target {
+        | Target::Dir(tg) => {
+            compile_name(&tg.name, tg.syntax, tg.case).map(Matcher::Dir)
+        },
+        | Target::File(tg) => compile_file(tg).map(Matcher::File),
+        | Target::Entry(tg) => compile_name(&tg.name, tg.syntax, tg.case)
+            .map(|name| Matcher::Entry(name, tg.kind)),
+        | Target::Custom(tg) => Ok(Matcher::Custom(tg.clone())),
+        | Target::All(targets) => compile(targets).map(Matcher::All),
+        | Target::Any(targets) => compile(targets).map(Matcher::Any),
+        | Target::Not(target) => {
+            compile_target(target).map(|m| Matcher::Not(Box::new(m)))
+        },
+    }
+}
+
+/// Prepare the targets for matching.
+pub(crate) fn compile(targets: &[Target]) -> io::Result<Vec<Matcher>> {
+    targets.iter().map(compile_target).collect()
+}
+
+#[cfg(unix)]
+fn is_special_kind(
+    file_type: fs::FileType,
+    kind: EntryKind,
+) -> bool {
+    use std::os::unix::fs::FileTypeExt as _;
+
+    match kind {
+        | EntryKind::Socket => file_type.is_socket(),
+        | EntryKind::Fifo => file_type.is_fifo(),
+        | EntryKind::BlockDevice => file_type.is_block_device(),
+        | EntryKind::CharDevice => file_type.is_char_device(),
+        | EntryKind::Any | EntryKind::Symlink => false,
+    }
+}
+
+#[cfg(not(unix))]
+fn is_special_kind(
+    _file_type: fs::FileType,
+    _kind: EntryKind,
+) -> bool {
+    false
+}
+
+/// Check the type of an entry without following symlinks.
+fn is_entry_type(
+    file_type: fs::FileType,
+    kind: EntryKind,
+) -> bool {
+    match kind {
+        | EntryKind::Any => true,
+        | EntryKind::Symlink => file_type.is_symlink(),
+        | EntryKind::Socket
+        | EntryKind::Fifo
+        | EntryKind::BlockDevice
+        | EntryKind::CharDevice => is_special_kind(file_type, kind),
+    }
+}
+
+async fn is_kind<F: Fs>(
+    fs: &F,
+    path: &Path,
+    kind: Kind,
+) -> bool {
+    let metadata: io::Result<fs::Metadata> = match kind {
+        | Kind::Dir | Kind::File => fs.metadata(path).await,
+        | Kind::Entry(_) => fs.symlink_metadata(path).await,
+    };
+
+    match metadata {
+        | Ok(metadata) => match kind {
+            | Kind::Dir => metadata.is_dir(),
+            | Kind::File => metadata.is_file(),
+            | Kind::Entry(kind) => is_entry_type(metadata.file_type(), kind),
+        },
+        | Err(_) => false,
+    }
+}
+
+/// The checks applied to a path whose name matches.
+#[derive(Debug, Clone, Copy)]
+enum Check<'a> {
+    Kind(Kind),
+    File(&'a FileMatcher),
+}
+
+impl Check<'_> {
+    fn kind(self) -> Kind {
+        match self {
+            | Check::Kind(kind) => kind,
+            | Check::File(_) => Kind::File,
+        }
+    }
+}
+
+#[cfg(unix)]
+fn is_mode_match(
+    metadata: &fs::Metadata,
+    constraints: &MetadataConstraints,
+) -> bool {
+    use std::os::unix::fs::PermissionsExt as _;
+
+    let mode: u32 = metadata.permissions().mode();
+
+    if let Some(bits) = constraints.mode {
+        if mode & bits != bits {
+            return false;
+        }
+    }
+
+    if let Some(executable) = constraints.executable {
+        if (mode & 0o111 != 0) != executable {
+            return false;
+        }
+    }
+
+    true
+}
+
+#[cfg(not(unix))]
+fn is_mode_match(
+    _metadata: &fs::Metadata,
+    constraints: &MetadataConstraints,
+) -> bool {
+    constraints.mode.is_none() && constraints.executable.is_none()
+}
+
+fn is_metadata_match(
+    metadata: &fs::Metadata,
+    constraints: &MetadataConstraints,
+) -> bool {
+    let size: u64 = metadata.len();
+
+    if constraints.min_size.is_some_and(|min| size < min)
+        || constraints.max_size.is_some_and(|max| size > max)
+    {
+        return false;
+    }
+
+    if constraints.modified_after.is_some()
+        || constraints.modified_before.is_some()
+    {
+        let Ok(modified) = metadata.modified() else {
+            return false;
+        };
+
+        if constraints.modified_after.is_some_and(|after| modified <= after)
+            || constraints
+                .modified_before
+                .is_some_and(|before| modified >= before)
+        {
+            return false;
+        }
+    }
+
+    is_mode_match(metadata, constraints)
+}
+
+async fn is_file_match<F: Fs>(
+    fs: &F,
+    path: &Path,
+    matcher: &FileMatcher,
+) -> bool {
+    if let Some(constraints) = &matcher.metadata {
+        match fs.metadata(path).await {
+            | Ok(metadata) => {
+                if !is_metadata_match(&metadata, constraints) {
+                    return false;
+                }
+            },
+            | Err(_) => return false,
+        }
+    }
+
+    if matcher.content.is_none() && matcher.structured.is_none() {
+        return true;
+    }
+
+    let Ok(bytes) = fs.read(path, matcher.read_limit).await else {
+        return false;
+    };
+
+    if let Some(content) = &matcher.content {
+        if !content.is_match(&bytes) {
+            return false;
+        }
+    }
+
+    if let Some(structured) = &matcher.structured {
+        if !is_structured_match(&bytes, structured) {
+            return false;
+        }
+    }
+
+    true
+}
+
+async fn is_constrained<F: Fs>(
+    fs: &F,
+    path: &Path,
+    check: Check<'_>,
+) -> bool {
+    match check {
+        | Check::Kind(_) => true,
+        | Check::File(matcher) => is_file_match(fs, path, matcher).await,
+    }
+}
+
+async fn is_path_match<F: Fs>(
+    fs: &F,
+    path: &Path,
+    check: Check<'_>,
+) -> bool {
+    is_kind(fs, path, check.kind()).await
+        && is_constrained(fs, path, check).await
+}
+
+async fn is_entry_match<F: Fs>(
+    fs: &F,
+    entry: &Entry,
+    check: Check<'_>,
+) -> bool {
+    is_entry_kind(fs, entry, check.kind()).await
+        && is_constrained(fs, &entry.path, check).await
+}
+
+async fn is_entry_kind<F: Fs>(
+    fs: &F,
+    entry: &Entry,
+    kind: Kind,
+) -> bool {
+    match kind {
+        | Kind::Entry(kind) => is_entry_type(entry.file_type, kind),
+        | Kind::Dir | Kind::File if entry.file_type.is_symlink() => {
+            is_kind(fs, &entry.path, kind).await
+        },
+        | Kind::Dir => entry.file_type.is_dir(),
+        | Kind::File => entry.file_type.is_file(),
+    }
+}
+
+fn find_components<'a, F: Fs>(
+    fs: &'a F,
+    dir: PathBuf,
+    components: &'a [Component],
+    case: Case,
+    check: Check<'a>,
+) -> BoxFuture<'a, Option<PathBuf>> {
+    Box::pin(async move {
+        let (component, rest) = components.split_first()?;
+
+        let entries: Vec<Entry> = fs.read_dir(&dir).await.unwrap_or_default();
+
+        match component {
+            | Component::Recursive => {
+                if let Some(path) =
+                    find_components(fs, dir, rest, case, check).await
+                {
+                    return Some(path);
+                }
+
+                for entry in entries {
+                    if !entry.file_type.is_dir() {
+                        continue;
+                    }
+
+                    if let Some(path) =
+                        find_components(fs, entry.path, components, case, check)
+                            .await
+                    {
+                        return Some(path);
+                    }
+                }
+            },
+            | Component::Exact(_) | Component::Pattern(_) => {
+                for entry in entries {
+                    let Some(name) = entry.name.to_str() else {
+                        continue;
+                    };
+
+                    let name: Cow<'_, str> = fold(name, case);
+
+                    let is_match: bool = match component {
+                        | Component::Exact(exact) => name == exact.as_str(),
+                        | Component::Pattern(pattern) => pattern.matches(&name),
+                        | Component::Recursive => false,
+                    };
+
+                    if !is_match {
+                        continue;
+                    }
+
+                    if rest.is_empty() {
+                        if is_entry_match(fs, &entry, check).await {
+                            return Some(entry.path);
+                        }
+                    } else if is_entry_kind(fs, &entry, Kind::Dir).await {
+                        if let Some(path) =
+                            find_components(fs, entry.path, rest, case, check)
+                                .await
+                        {
+                            return Some(path);
+                        }
+                    }
+                }
+            },
+        }
+
+        None
+    })
+}
+
+async fn find_regex<F: Fs>(
+    fs: &F,
+    entries: &[Entry],
+    regex: &regex::Regex,
+    case: Case,
+    check: Check<'_>,
+) -> Option<PathBuf> {
+    for entry in entries {
+        let Some(name) = entry.name.to_str() else {
+            continue;
+        };
+
+        let name: Cow<'_, str> = match case {
+            | Case::Insensitive => Cow::Owned(name.nfc().collect()),
+            | Case::Sensitive | Case::AsciiInsensitive => Cow::Borrowed(name),
+        };
+
+        if regex.is_match(&name) && is_entry_match(fs, entry, check).await {
+            return Some(entry.path.clone());
+        }
+    }
+
+    None
+}
+
+async fn find_name<F: Fs>(
+    fs: &F,
+    dir: &Path,
+    entries: &mut Option<Vec<Entry>>,
+    name: &Name,
+    check: Check<'_>,
+) -> Option<PathBuf> {
+    match name {
+        | Name::Literal(name) => {
+            let path: PathBuf = dir.join(name);
+
+            is_path_match(fs, &path, check).await.then_some(path)
+        },
+        | Name::Components(components, case) => {
+            find_components(fs, dir.to_path_buf(), components, *case, check)
+                .await
+        },
+        | Name::Regex(regex, case) => {
+            if entries.is_none() {
+                *entries = Some(fs.read_dir(dir).await.unwrap_or_default());
+            }
+
+            find_regex(
+                fs,
+                entries.as_deref().unwrap_or_default(),
+                regex,
+                *case,
+                check,
+            )
+            .await
+        },
+    }
+}
+
+/// Find the entry matching the target in the directory.
+///
+/// The combinators and the custom targets match the directory itself, except
+/// that [`Matcher::All`] and [`Matcher::Any`] yield the entry of their last
+/// matching target.
+fn find_target<'a, F: Fs>(
+    fs: &'a F,
+    dir: &'a Path,
+    entries: &'a mut Option<Vec<Entry>>,
+    matcher: &'a Matcher,
+) -> BoxFuture<'a, Option<PathBuf>> {
+    Box::pin(async move {
+        match matcher {
+            | Matcher::Dir(name) => {
+                find_name(fs, dir, entries, name, Check::Kind(Kind::Dir)).await
+            },
+            | Matcher::File(matcher) => {
+                find_name(fs, dir, entries, &matcher.name, Check::File(matcher))
+                    .await
+            },
+            | Matcher::Entry(name, kind) => {
+                find_name(
+                    fs,
+                    dir,
+                    entries,
+                    name,
+                    Check::Kind(Kind::Entry(*kind)),
+                )
+                .await
+            },
+            | Matcher::Custom(tg) => {
+                tg.is_match(dir).then(|| dir.to_path_buf())
+            },
+            | Matcher::All(matchers) => {
+                let mut path: PathBuf = dir.to_path_buf();
+
+                for matcher in matchers {
+                    path = find_target(fs, dir, entries, matcher).await?;
+                }
+
+                Some(path)
+            },
+            | Matcher::Any(matchers) => {
+                for matcher in matchers {
+                    if let Some(path) =
+                        find_target(fs, dir, entries, matcher).await
+                    {
+                        return Some(path);
+                    }
+                }
+
+                None
+            },
+            | Matcher::Not(matcher) => find_target(fs, dir, entries, matcher)
+                .await
+                .is_none()
+                .then(|| dir.to_path_buf()),
+        }
+    })
+}
+
+/// Find the first of the targets in the directory.
+///
+/// It returns the index of the target and the path of the matched entry.
+pub(crate) async fn find_targets<F: Fs>(
+    fs: &F,
+    dir: &Path,
+    matchers: &[Matcher],
+) -> Option<(usize, PathBuf)> {
+    let mut entries: Option<Vec<Entry>> = None;
+
+    for (index, matcher) in matchers.iter().enumerate() {
+        if let Some(path) = find_target(fs, dir, &mut entries, matcher).await {
+            return Some((index, path));
+        }
+    }
+
+    None
+}
+
+/// Check whether the directory contains any of the targets.
+pub(crate) async fn is_targets_exist<F: Fs>(
+    fs: &F,
+    dir: &Path,
+    matchers: &[Matcher],
+) -> bool {
+    find_targets(fs, dir, matchers).await.is_some()
+}
@@ -7,83 +7,76 @@ pub mod smol;
 #[cfg(feature = "tokio")]
 pub mod tokio;
 
-use std::{
-    collections::VecDeque,
-    env::current_dir,
-    fs, io,
-    path::{Path, PathBuf},
-};
+pub(crate) mod file_system;
 
-use crate::structs::target::Target;
+pub(crate) mod filter;
 
-fn is_target_exists(
-    path: &Path,
-    target: &Target,
-) -> bool {
-    match target {
-        | Target::Dir(tg) => path.join(&tg.name).is_dir(),
-        | Target::File(tg) => path.join(&tg.name).is_file(),
-    }
-}
+#[cfg(feature = "ignore")]
+pub(crate) mod ignore_files;
 
-pub(crate) fn is_targets_exist(
-    dir: &Path,
-    targets: &[Target],
-) -> bool {
-    targets.iter().any(|t| is_target_exists(dir, t))
-}
+pub(crate) mod iter;
 
-fn get_dir(options: GetDir) -> io::Result<PathBuf> {
-    let GetDir { dir, depth, targets } = options;
+pub(crate) mod matcher;
 
-    if depth == 0 {
-        return Err(io::Error::from(io::ErrorKind::NotFound));
-    }
+pub(crate) mod structured;
 
-    let mut queue: VecDeque<(PathBuf, usize)> = VecDeque::new();
+pub(crate) mod walker;
 
-    queue.push_back((dir, depth));
+use std::{env::current_dir, path::PathBuf, time::Duration};
 
-    while let Some((current_dir, remaining_depth)) = queue.pop_front() {
-        if is_targets_exist(&current_dir, &targets) {
-            return Ok(current_dir);
-        }
+use crate::{
+    structs::{
+        boundary::Boundary, cancel_token::CancelToken, error::GetDirError,
+        follow_links::FollowLinks, matched::Match, outcome::Outcome,
+        strategy::Strategy, target::Target,
+    },
+    util::{
+        file_system::{SyncFs, block_on},
+        iter::Iter,
+        walker::{Ancestors, Search, Walker, nearest},
+    },
+};
 
-        if remaining_depth <= 1 {
-            continue;
-        }
+fn get_dir(options: GetDir) -> Result<PathBuf, GetDirError> {
+    get_dir_detailed(options).map(|found| found.dir)
+}
 
-        let entries: fs::ReadDir = match fs::read_dir(&current_dir) {
-            | Ok(e) => e,
-            | Err(_) => continue,
-        };
+fn get_dir_detailed(options: GetDir) -> Result<Match, GetDirError> {
+    let walker: Walker<SyncFs> = Walker::new(SyncFs, options)?;
 
-        for entry in entries.flatten() {
-            let path: PathBuf = entry.path();
+    block_on(walker.first())
+}
 
-            if path.is_dir() {
-                queue.push_back((path, remaining_depth - 1));
-            }
-        }
-    }
+fn get_dir_all(options: GetDir) -> Result<Vec<PathBuf>, GetDirError> {
+    let walker: Walker<SyncFs> = Walker::new(SyncFs, options)?;
 
-    Err(io::Error::from(io::ErrorKind::NotFound))
+    block_on(walker.collect())
 }
 
-fn get_dir_reverse(options: GetDir) -> io::Result<PathBuf> {
-    let GetDir { dir, depth, targets } = options;
+fn get_dir_reverse(options: GetDir) -> Result<PathBuf, GetDirError> {
+    get_dir_reverse_detailed(options).map(|found| found.dir)
+}
 
-    for (i, ancestor) in dir.ancestors().enumerate() {
-        if i >= depth {
-            break;
-        }
+fn get_dir_reverse_detailed(options: GetDir) -> Result<Match, GetDirError> {
+    let ancestors: Ancestors<SyncFs> = Ancestors::new(SyncFs, options)?;
 
-        if is_targets_exist(ancestor, &targets) {
-            return Ok(ancestor.to_path_buf());
-        }
-    }
+    block_on(ancestors.first())
+}
+
+fn get_dir_reverse_bounded(options: GetDir) -> Result<Outcome, GetDirError> {
+    let ancestors: Ancestors<SyncFs> = Ancestors::new(SyncFs, options)?;
+
+    block_on(ancestors.outcome())
+}
+
+fn get_dir_reverse_outermost(options: GetDir) -> Result<PathBuf, GetDirError> {
+    let ancestors: Ancestors<SyncFs> = Ancestors::new(SyncFs, options)?;
 
-    Err(io::Error::from(io::ErrorKind::NotFound))
+    block_on(ancestors.last()).map(|found| found.dir)
+}
+
+fn get_dir_nearest(options: GetDir) -> Result<PathBuf, GetDirError> {
+    block_on(nearest(&SyncFs, options)).map(|found| found.dir)
 }
 
 /// Utility to get directory.
@@ -114,6 +107,63 @@ pub struct GetDir {
     pub depth: usize,
     /// The targets to search.
     pub targets: Vec<Target>,
+    /// The boundaries of the search upward.
+    ///
+    /// By default, it is empty.
+    pub boundaries: Vec<Boundary>,
+    /// The patterns of the directories to exclude from the forward search.
+    ///
+    /// By default, it is empty.
+    pub exclude: Vec<String>,
+    /// The patterns of the only directories to search in the forward search.
+    ///
+    /// By default, it is empty, which searches every directory.
+    pub include_only: Vec<String>,
+    /// Whether to skip the hidden directories in the forward search or not.
+    ///
+    /// By default, it is `false`.
+    pub skip_hidden: bool,
+    /// The symlinks to directories the forward search follows.
+    ///
+    /// By default, it is [`FollowLinks::Always`].
+    pub follow_links: FollowLinks,
+    /// Whether to stay on the file system of the directory or not.
+    ///
+    /// By default, it is `false`.
+    pub same_file_system: bool,
+    /// Whether to skip the directories ignored by ignore files or not,
+    /// requires `ignore` feature.
+    ///
+    /// Without the feature, it has no effect.
+    ///
+    /// By default, it is `false`.
+    pub respect_ignore_files: bool,
+    /// The order of the forward search.
+    ///
+    /// By default, it is [`Strategy::Bfs`].
+    pub strategy: Strategy,
+    /// The maximum number of levels to search upward in the nearest search.
+    ///
+    /// Like the `depth`, it counts the directory itself, so `1` searches only
+    /// the directory and its descendants.
+    ///
+    /// By default, it is [`usize::MAX`].
+    pub up_depth: usize,
+    /// The maximum number of levels to search downward in the nearest search.
+    ///
+    /// Like the `depth`, it counts each ancestor itself, so `1` searches only
+    /// the ancestors.
+    ///
+    /// By default, it is [`usize::MAX`].
+    pub down_depth: usize,
+    /// The maximum duration of the search.
+    ///
+    /// By default, it is [`None`], which never times out.
+    pub timeout: Option<Duration>,
+    /// The token to cancel the search with.
+    ///
+    /// By default, it is [`None`].
+    pub cancel_token: Option<CancelToken>,
 }
 
 impl GetDir {
@@ -138,6 +188,18 @@ impl GetDir {
             },
             depth: usize::MAX,
             targets: Vec::new(),
+            boundaries: Vec::new(),
+            exclude: Vec::new(),
+            include_only: Vec::new(),
+            skip_hidden: false,
+            follow_links: FollowLinks::Always,
+            same_file_system: false,
+            respect_ignore_files: false,
+            strategy: Strategy::Bfs,
+            up_depth: usize::MAX,
+            down_depth: usize::MAX,
+            timeout: None,
+            cancel_token: None,
         }
     }
 
@@ -203,6 +265,9 @@ impl GetDir {
 
     /// Add targets to the search.
     ///
+    /// A directory matches when it contains any of the targets, as with
+    /// [`Target::Any`].
+    ///
     /// ## Example
     ///
     /// ```no_run
@@ -261,6 +326,415 @@ impl GetDir {
         self
     }
 
+    /// Add boundaries to the search upward.
+    ///
+    /// The reverse and nearest searches examine a boundary directory, but
+    /// never go past it.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::path::PathBuf;
+    ///
+    /// use get_dir::{
+    ///     Boundary,
+    ///     GetDir,
+    ///     Target,
+    ///     DirTarget,
+    ///     FileTarget,
+    /// };
+    ///
+    /// let path: PathBuf = GetDir::new()
+    ///     .target(Target::File(FileTarget::new(".env")))
+    ///     .boundaries([
+    ///         Boundary::Target(Target::Dir(DirTarget::new(".git"))),
+    ///         Boundary::Path(PathBuf::from("/home")),
+    ///     ])
+    ///     .run_reverse()
+    ///     .unwrap();
+    /// ```
+    pub fn boundaries<BS>(
+        mut self,
+        boundaries: BS,
+    ) -> Self
+    where
+        BS: IntoIterator<Item = Boundary>,
+    {
+        self.boundaries.extend(boundaries);
+        self
+    }
+
+    /// Add a boundary to the search upward.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::path::PathBuf;
+    ///
+    /// use get_dir::{
+    ///     Boundary,
+    ///     GetDir,
+    ///     Target,
+    ///     DirTarget,
+    /// };
+    ///
+    /// let path: PathBuf = GetDir::new()
+    ///     .boundary(Boundary::Target(Target::Dir(DirTarget::new(".git"))))
+    ///     .run_reverse()
+    ///     .unwrap();
+    /// ```
+    pub fn boundary(
+        mut self,
+        boundary: Boundary,
+    ) -> Self {
+        self.boundaries.push(boundary);
+        self
+    }
+
+    /// Add patterns of the directories to exclude from the forward search.
+    ///
+    /// The patterns are globs relative to the directory the search starts in.
+    /// A pattern without `/` matches a directory name at any depth, such as
+    /// `node_modules` or `.*`. An excluded directory is neither searched nor
+    /// descended into.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::path::PathBuf;
+    ///
+    /// use get_dir::GetDir;
+    ///
+    /// let path: PathBuf = GetDir::new()
+    ///     .exclude(["node_modules", "target", ".*"])
+    ///     .run()
+    ///     .unwrap();
+    /// ```
+    pub fn exclude<PS, P>(
+        mut self,
+        patterns: PS,
+    ) -> Self
+    where
+        PS: IntoIterator<Item = P>,
+        P: Into<String>,
+    {
+        self.exclude.extend(patterns.into_iter().map(|p| p.into()));
+        self
+    }
+
+    /// Add patterns of the only directories to search in the forward search.
+    ///
+    /// The patterns follow the same rules as [`GetDir::exclude`]. Other
+    /// directories are only descended into if they may contain a matching
+    /// directory, and the directory the search starts in is not searched.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::path::PathBuf;
+    ///
+    /// use get_dir::GetDir;
+    ///
+    /// let path: PathBuf = GetDir::new()
+    ///     .include_only(["crates/*"])
+    ///     .run()
+    ///     .unwrap();
+    /// ```
+    pub fn include_only<PS, P>(
+        mut self,
+        patterns: PS,
+    ) -> Self
+    where
+        PS: IntoIterator<Item = P>,
+        P: Into<String>,
+    {
+        self.include_only.extend(patterns.into_iter().map(|p| p.into()));
+        self
+    }
+
+    /// Set whether to skip the hidden directories in the forward search or
+    /// not.
+    ///
+    /// If enabled, the directories whose name starts with `.` are neither
+    /// searched nor descended into, which also applies to the descendants in
+    /// the nearest search. The directory the search starts in and its
+    /// ancestors are still searched, and targets such as `.git` still match.
+    ///
+    /// By default, it is `false`.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::path::PathBuf;
+    ///
+    /// use get_dir::{
+    ///     GetDir,
+    ///     Target,
+    ///     DirTarget,
+    /// };
+    ///
+    /// let path: PathBuf = GetDir::new()
+    ///     .target(Target::Dir(DirTarget::new(".git")))
+    ///     .skip_hidden(true)
+    ///     .run()
+    ///     .unwrap();
+    /// ```
+    pub fn skip_hidden(
+        mut self,
+        skip_hidden: bool,
+    ) -> Self {
+        self.skip_hidden = skip_hidden;
+        self
+    }
+
+    /// Set the symlinks to directories the forward search follows.
+    ///
+    /// Whatever the policy is, a symlink to one of its own ancestors is not
+    /// descended into, so symlink cycles do not loop. A directory reached
+    /// through several paths is still returned once.
+    ///
+    /// By default, it is [`FollowLinks::Always`].
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::path::PathBuf;
+    ///
+    /// use get_dir::{
+    ///     FollowLinks,
+    ///     GetDir,
+    /// };
+    ///
+    /// let path: PathBuf = GetDir::new()
+    ///     .follow_links(FollowLinks::Never)
+    ///     .run()
+    ///     .unwrap();
+    /// ```
+    pub fn follow_links(
+        mut self,
+        follow_links: FollowLinks,
+    ) -> Self {
+        self.follow_links = follow_links;
+        self
+    }
+
+    /// Set whether to stay on the file system of the directory or not.
+    ///
+    /// If enabled, the search neither descends into nor ascends across a
+    /// directory on another device, such as a mount point. It has no effect
+    /// on non-Unix platforms.
+    ///
+    /// By default, it is `false`.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::path::PathBuf;
+    ///
+    /// use get_dir::GetDir;
+    ///
+    /// let path: PathBuf = GetDir::new()
+    ///     .directory("/")
+    ///     .same_file_system(true)
+    ///     .run()
+    ///     .unwrap();
+    /// ```
+    pub fn same_file_system(
+        mut self,
+        same_file_system: bool,
+    ) -> Self {
+        self.same_file_system = same_file_system;
+        self
+    }
+
+    /// Set whether to skip the directories ignored by ignore files or not,
+    /// requires `ignore` feature.
+    ///
+    /// If enabled, the forward search reads the `.gitignore` and `.ignore`
+    /// files of each directory, and inside a git repository, the
+    /// `.git/info/exclude` file and the global excludes file of git. The
+    /// ignored directories are neither searched nor descended into, and the
+    /// rules of a deeper directory take precedence. The nearest search
+    /// applies them to the descendants of each ancestor.
+    ///
+    /// By default, it is `false`.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::path::PathBuf;
+    ///
+    /// use get_dir::{
+    ///     GetDir,
+    ///     Target,
+    ///     FileTarget,
+    /// };
+    ///
+    /// let paths: Vec<PathBuf> = GetDir::new()
+    ///     .target(Target::File(FileTarget::new("Cargo.toml")))
+    ///     .respect_ignore_files(true)
+    ///     .run_all()
+    ///     .unwrap();
+    /// ```
+    #[cfg(feature = "ignore")]
+    pub fn respect_ignore_files(
+        mut self,
+        respect_ignore_files: bool,
+    ) -> Self {
+        self.respect_ignore_files = respect_ignore_files;
+        self
+    }
+
+    /// Set the order of the forward search.
+    ///
+    /// Each strategy respects the depth in the same way.
+    ///
+    /// By default, it is [`Strategy::Bfs`].
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::path::PathBuf;
+    ///
+    /// use get_dir::{
+    ///     GetDir,
+    ///     Strategy,
+    /// };
+    ///
+    /// let path: PathBuf = GetDir::new()
+    ///     .strategy(Strategy::Dfs)
+    ///     .run()
+    ///     .unwrap();
+    /// ```
+    pub fn strategy(
+        mut self,
+        strategy: Strategy,
+    ) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Set the maximum number of levels to search upward in the nearest
+    /// search.
+    ///
+    /// Like [`GetDir::depth`], it counts the directory itself, so `1`
+    /// searches only the directory and its descendants, `2` searches its
+    /// parent as well, and `0` searches nothing.
+    ///
+    /// By default, it is [`usize::MAX`].
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::path::PathBuf;
+    ///
+    /// use get_dir::GetDir;
+    ///
+    /// let path: PathBuf = GetDir::new()
+    ///     .up_depth(3)
+    ///     .run_nearest()
+    ///     .unwrap();
+    /// ```
+    pub fn up_depth(
+        mut self,
+        depth: usize,
+    ) -> Self {
+        self.up_depth = depth;
+        self
+    }
+
+    /// Set the maximum number of levels to search downward in the nearest
+    /// search.
+    ///
+    /// It is counted from each ancestor, so it also limits how far the
+    /// siblings of the ancestors are searched. Like [`GetDir::depth`], it
+    /// counts the ancestor itself, so `1` searches only the ancestors, `2`
+    /// searches their children as well, and `0` searches nothing.
+    ///
+    /// By default, it is [`usize::MAX`].
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::path::PathBuf;
+    ///
+    /// use get_dir::GetDir;
+    ///
+    /// let path: PathBuf = GetDir::new()
+    ///     .down_depth(2)
+    ///     .run_nearest()
+    ///     .unwrap();
+    /// ```
+    pub fn down_depth(
+        mut self,
+        depth: usize,
+    ) -> Self {
+        self.down_depth = depth;
+        self
+    }
+
+    /// Set the maximum duration of the search.
+    ///
+    /// The search fails with [`GetDirError::Timeout`] once the duration has
+    /// passed. It is checked before each directory, so a single slow read is
+    /// not interrupted. The duration starts when the search is created, such
+    /// as when [`GetDir::iter`] is called.
+    ///
+    /// By default, it is [`None`], which never times out.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::{path::PathBuf, time::Duration};
+    ///
+    /// use get_dir::GetDir;
+    ///
+    /// let path: PathBuf = GetDir::new()
+    ///     .timeout(Duration::from_secs(5))
+    ///     .run()
+    ///     .unwrap();
+    /// ```
+    pub fn timeout(
+        mut self,
+        timeout: Duration,
+    ) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set the token to cancel the search with.
+    ///
+    /// Once the token is cancelled, the search fails with
+    /// [`GetDirError::Cancelled`] before checking the next directory. An
+    /// iterator or a stream yields the error once and then ends.
+    ///
+    /// By default, it is [`None`].
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::path::PathBuf;
+    ///
+    /// use get_dir::{
+    ///     CancelToken,
+    ///     GetDir,
+    /// };
+    ///
+    /// let token: CancelToken = CancelToken::new();
+    ///
+    /// let path: PathBuf = GetDir::new()
+    ///     .cancel_token(token.clone())
+    ///     .run()
+    ///     .unwrap();
+    /// ```
+    pub fn cancel_token(
+        mut self,
+        token: CancelToken,
+    ) -> Self {
+        self.cancel_token = Some(token);
+        self
+    }
+
     /// Get the first directory containing any of the specified targets.
     ///
     /// ## Example
@@ -274,10 +748,120 @@ impl GetDir {
     ///     .run()
     ///     .unwrap();
     /// ```
-    pub fn run(self) -> io::Result<PathBuf> {
+    pub fn run(self) -> Result<PathBuf, GetDirError> {
         get_dir(self)
     }
 
+    /// Get the first directory containing any of the specified targets,
+    /// along with the matched target and entry.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use get_dir::{
+    ///     GetDir,
+    ///     Match,
+    ///     Target,
+    ///     DirTarget,
+    ///     FileTarget,
+    /// };
+    ///
+    /// let found: Match = GetDir::new()
+    ///     .targets([
+    ///         Target::File(FileTarget::new("Cargo.toml")),
+    ///         Target::Dir(DirTarget::new(".git")),
+    ///     ])
+    ///     .run_detailed()
+    ///     .unwrap();
+    /// ```
+    pub fn run_detailed(self) -> Result<Match, GetDirError> {
+        get_dir_detailed(self)
+    }
+
+    /// Get every directory containing any of the specified targets.
+    ///
+    /// The directories are returned in the order of [`GetDir::strategy`],
+    /// and a directory reached through several paths is returned once. If
+    /// nothing matches, the result is empty, unless the directory cannot be
+    /// read.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::path::PathBuf;
+    ///
+    /// use get_dir::{
+    ///     GetDir,
+    ///     Target,
+    ///     FileTarget,
+    /// };
+    ///
+    /// let paths: Vec<PathBuf> = GetDir::new()
+    ///     .target(Target::File(FileTarget::new("Cargo.toml")))
+    ///     .run_all()
+    ///     .unwrap();
+    /// ```
+    pub fn run_all(self) -> Result<Vec<PathBuf>, GetDirError> {
+        get_dir_all(self)
+    }
+
+    /// Get an iterator over the directories containing any of the specified
+    /// targets.
+    ///
+    /// The search runs lazily in the order of [`GetDir::strategy`], so it
+    /// stops as soon as the iterator is no longer advanced. An invalid target
+    /// is yielded as the only error, and so is a directory the search could
+    /// not start in when nothing is found. A cancelled or timed out search
+    /// yields [`GetDirError::Cancelled`] or [`GetDirError::Timeout`] after the
+    /// matches found so far, then ends.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use get_dir::{
+    ///     GetDir,
+    ///     Match,
+    ///     Target,
+    ///     FileTarget,
+    /// };
+    ///
+    /// let matches: Vec<Match> = GetDir::new()
+    ///     .target(Target::File(FileTarget::new("Cargo.toml")))
+    ///     .iter()
+    ///     .take(2)
+    ///     .collect::<Result<_, _>>()
+    ///     .unwrap();
+    /// ```
+    pub fn iter(self) -> Iter {
+        Iter::new(Walker::new(SyncFs, self).map(Search::Forward))
+    }
+
+    /// Get an iterator over the ancestors containing any of the specified
+    /// targets.
+    ///
+    /// The ancestors are yielded from nearest to farthest, starting with the
+    /// directory itself, and at most `depth` of them are examined.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use get_dir::{
+    ///     GetDir,
+    ///     Match,
+    ///     Target,
+    ///     FileTarget,
+    /// };
+    ///
+    /// let matches: Vec<Match> = GetDir::new()
+    ///     .target(Target::File(FileTarget::new("Cargo.toml")))
+    ///     .iter_reverse()
+    ///     .collect::<Result<_, _>>()
+    ///     .unwrap();
+    /// ```
+    pub fn iter_reverse(self) -> Iter {
+        Iter::new(Ancestors::new(SyncFs, self).map(Search::Reverse))
+    }
+
     /// Get the first directory containing any of the specified targets in reverse.
     ///
     /// ## Example
@@ -291,9 +875,121 @@ impl GetDir {
     ///     .run_reverse()
     ///     .unwrap();
     /// ```
-    pub fn run_reverse(self) -> io::Result<PathBuf> {
+    pub fn run_reverse(self) -> Result<PathBuf, GetDirError> {
         get_dir_reverse(self)
     }
+
+    /// Get the first directory containing any of the specified targets in
+    /// reverse, along with the matched target and entry.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use get_dir::{
+    ///     GetDir,
+    ///     Match,
+    ///     Target,
+    ///     DirTarget,
+    ///     FileTarget,
+    /// };
+    ///
+    /// let found: Match = GetDir::new()
+    ///     .targets([
+    ///         Target::File(FileTarget::new("Cargo.toml")),
+    ///         Target::Dir(DirTarget::new(".git")),
+    ///     ])
+    ///     .run_reverse_detailed()
+    ///     .unwrap();
+    /// ```
+    pub fn run_reverse_detailed(self) -> Result<Match, GetDirError> {
+        get_dir_reverse_detailed(self)
+    }
+
+    /// Get the first directory containing any of the specified targets in
+    /// reverse, or how the search ended without one.
+    ///
+    /// Unlike [`GetDir::run_reverse`], it tells whether the search stopped at
+    /// a boundary, at the root or because of the depth.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use get_dir::{
+    ///     Boundary,
+    ///     GetDir,
+    ///     Outcome,
+    ///     Target,
+    ///     DirTarget,
+    ///     FileTarget,
+    /// };
+    ///
+    /// let outcome: Outcome = GetDir::new()
+    ///     .target(Target::File(FileTarget::new(".env")))
+    ///     .boundary(Boundary::Target(Target::Dir(DirTarget::new(".git"))))
+    ///     .run_reverse_bounded()
+    ///     .unwrap();
+    /// ```
+    pub fn run_reverse_bounded(self) -> Result<Outcome, GetDirError> {
+        get_dir_reverse_bounded(self)
+    }
+
+    /// Get the farthest ancestor containing any of the specified targets.
+    ///
+    /// The `depth` is the maximum number of ancestors examined, starting with
+    /// the directory itself.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::path::PathBuf;
+    ///
+    /// use get_dir::{
+    ///     GetDir,
+    ///     Target,
+    ///     FileTarget,
+    /// };
+    ///
+    /// let path: PathBuf = GetDir::new()
+    ///     .target(Target::File(
+    ///         FileTarget::new("Cargo.toml").contains("[workspace]"),
+    ///     ))
+    ///     .run_reverse_outermost()
+    ///     .unwrap();
+    /// ```
+    pub fn run_reverse_outermost(self) -> Result<PathBuf, GetDirError> {
+        get_dir_reverse_outermost(self)
+    }
+
+    /// Get the nearest directory containing any of the specified targets.
+    ///
+    /// The ancestors and the descendants are searched together, including the
+    /// siblings of the ancestors, and the directory with the shortest tree
+    /// distance is returned. The [`GetDir::up_depth`] and
+    /// [`GetDir::down_depth`] are used instead of [`GetDir::depth`], and the
+    /// patterns of [`GetDir::exclude`] and [`GetDir::include_only`] filter
+    /// the descendants of each ancestor relative to that ancestor.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::path::PathBuf;
+    ///
+    /// use get_dir::{
+    ///     GetDir,
+    ///     Target,
+    ///     FileTarget,
+    /// };
+    ///
+    /// let path: PathBuf = GetDir::new()
+    ///     .target(Target::File(FileTarget::new(".env")))
+    ///     .up_depth(2)
+    ///     .down_depth(2)
+    ///     .run_nearest()
+    ///     .unwrap();
+    /// ```
+    pub fn run_nearest(self) -> Result<PathBuf, GetDirError> {
+        get_dir_nearest(self)
+    }
 }
 
 impl Default for GetDir {
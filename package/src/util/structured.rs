@@ -0,0 +1,152 @@
+#[cfg(any(feature = "toml", feature = "json", feature = "yaml"))]
+use crate::structs::target::structured::Format;
+use crate::structs::target::structured::Structured;
+
+#[cfg(feature = "toml")]
+fn lookup_toml(
+    text: &str,
+    segments: &[&str],
+) -> Option<Option<String>> {
+    let mut value: toml::Value =
+        toml::Value::Table(toml::from_str::<toml::Table>(text).ok()?);
+
+    for segment in segments {
+        value = match value {
+            | toml::Value::Table(mut table) => table.remove(*segment)?,
+            | toml::Value::Array(mut array) => {
+                let index: usize = segment.parse().ok()?;
+
+                if index >= array.len() {
+                    return None;
+                }
+
+                array.swap_remove(index)
+            },
+            | _ => return None,
+        };
+    }
+
+    Some(match value {
+        | toml::Value::String(s) => Some(s),
+        | toml::Value::Integer(i) => Some(i.to_string()),
+        | toml::Value::Float(f) => Some(f.to_string()),
+        | toml::Value::Boolean(b) => Some(b.to_string()),
+        | toml::Value::Datetime(d) => Some(d.to_string()),
+        | toml::Value::Array(_) | toml::Value::Table(_) => None,
+    })
+}
+
+#[cfg(feature = "json")]
+fn lookup_json(
+    text: &str,
+    segments: &[&str],
+) -> Option<Option<String>> {
+    let mut value: serde_json::Value = serde_json::from_str(text).ok()?;
+
+    for segment in segments {
+        value = match value {
+            | serde_json::Value::Object(mut object) => {
+                object.remove(*segment)?
+            },
+            | serde_json::Value::Array(mut array) => {
+                let index: usize = segment.parse().ok()?;
+
+                if index >= array.len() {
+                    return None;
+                }
+
+                array.swap_remove(index)
+            },
+            | _ => return None,
+        };
+    }
+
+    Some(match value {
+        | serde_json::Value::String(s) => Some(s),
+        | serde_json::Value::Number(n) => Some(n.to_string()),
+        | serde_json::Value::Bool(b) => Some(b.to_string()),
+        | serde_json::Value::Null => Some("null".to_string()),
+        | serde_json::Value::Array(_) | serde_json::Value::Object(_) => None,
+    })
+}
+
+#[cfg(feature = "yaml")]
+fn lookup_yaml(
+    text: &str,
+    segments: &[&str],
+) -> Option<Option<String>> {
+    use yaml_rust2::{Yaml, YamlLoader};
+
+    let mut value: Yaml =
+        YamlLoader::load_from_str(text).ok()?.into_iter().next()?;
+
+    for segment in segments {
+        value = match value {
+            | Yaml::Hash(mut hash) => {
+                hash.remove(&Yaml::String(segment.to_string()))?
+            },
+            | Yaml::Array(mut array) => {
+                let index: usize = segment.parse().ok()?;
+
+                if index >= array.len() {
+                    return None;
+                }
+
+                array.swap_remove(index)
+            },
+            | _ => return None,
+        };
+    }
+
+    Some(match value {
+        | Yaml::String(s) | Yaml::Real(s) => Some(s),
+        | Yaml::Integer(i) => Some(i.to_string()),
+        | Yaml::Boolean(b) => Some(b.to_string()),
+        | Yaml::Null => Some("null".to_string()),
+        | _ => None,
+    })
+}
+
+/// Check whether the structured file content contains the key.
+///
+/// Content that cannot be parsed does not match.
+#[cfg(any(feature = "toml", feature = "json", feature = "yaml"))]
+pub(crate) fn is_structured_match(
+    bytes: &[u8],
+    structured: &Structured,
+) -> bool {
+    let Ok(text) = std::str::from_utf8(bytes) else {
+        return false;
+    };
+
+    let segments: Vec<&str> = structured.key.split('.').collect();
+
+    let found: Option<Option<String>> = match structured.format {
+        #[cfg(feature = "toml")]
+        | Format::Toml => lookup_toml(text, &segments),
+        #[cfg(feature = "json")]
+        | Format::Json => lookup_json(text, &segments),
+        #[cfg(feature = "yaml")]
+        | Format::Yaml => lookup_yaml(text, &segments),
+        // Formats without their feature are rejected when compiling.
+        #[allow(unreachable_patterns)]
+        | _ => None,
+    };
+
+    match (found, &structured.value) {
+        | (None, _) => false,
+        | (Some(_), None) => true,
+        | (Some(actual), Some(expected)) => {
+            actual.as_deref() == Some(expected.as_str())
+        },
+    }
+}
+
+/// Structured keys are rejected when compiling without a format feature.
+#[cfg(not(any(feature = "toml", feature = "json", feature = "yaml")))]
+pub(crate) fn is_structured_match(
+    _bytes: &[u8],
+    _structured: &Structured,
+) -> bool {
+    false
+}
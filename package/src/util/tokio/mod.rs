@@ -1,60 +1,119 @@
-use std::{collections::VecDeque, path::PathBuf};
+use std::{
+    fs::Metadata,
+    path::{Path, PathBuf},
+};
 
-use tokio::{fs, io};
+use tokio::{
+    fs,
+    io::{self, AsyncReadExt as _},
+};
 
-use crate::util::{GetDir, is_targets_exist};
+use crate::{
+    structs::{error::GetDirError, matched::Match, outcome::Outcome},
+    util::{
+        GetDir,
+        file_system::{Entry, Fs},
+        walker::{Ancestors, Search, Walker, nearest, stream},
+    },
+};
 
-async fn get_dir(options: GetDir) -> io::Result<PathBuf> {
-    let GetDir { dir, depth, targets } = options;
+struct TokioFs;
 
-    if depth == 0 {
-        return Err(io::Error::from(io::ErrorKind::NotFound));
-    }
+impl Fs for TokioFs {
+    async fn read_dir(
+        &self,
+        path: &Path,
+    ) -> io::Result<Vec<Entry>> {
+        let mut entries: fs::ReadDir = fs::read_dir(path).await?;
+        let mut result: Vec<Entry> = Vec::new();
 
-    let mut queue: VecDeque<(PathBuf, usize)> = VecDeque::new();
+        while let Some(entry) = entries.next_entry().await? {
+            if let Ok(file_type) = entry.file_type().await {
+                result.push(Entry {
+                    name: entry.file_name(),
+                    path: entry.path(),
+                    file_type,
+                });
+            }
+        }
 
-    queue.push_back((dir, depth));
+        Ok(result)
+    }
 
-    while let Some((current_dir, remaining_depth)) = queue.pop_front() {
-        if is_targets_exist(&current_dir, &targets) {
-            return Ok(current_dir);
-        }
+    async fn metadata(
+        &self,
+        path: &Path,
+    ) -> io::Result<Metadata> {
+        fs::metadata(path).await
+    }
 
-        if remaining_depth <= 1 {
-            continue;
-        }
+    async fn symlink_metadata(
+        &self,
+        path: &Path,
+    ) -> io::Result<Metadata> {
+        fs::symlink_metadata(path).await
+    }
 
-        let mut entries: fs::ReadDir = match fs::read_dir(&current_dir).await {
-            | Ok(e) => e,
-            | Err(_) => continue,
-        };
+    async fn canonicalize(
+        &self,
+        path: &Path,
+    ) -> io::Result<PathBuf> {
+        fs::canonicalize(path).await
+    }
 
-        while let Some(entry) = entries.next_entry().await? {
-            let path: PathBuf = entry.path();
+    async fn read(
+        &self,
+        path: &Path,
+        limit: u64,
+    ) -> io::Result<Vec<u8>> {
+        let mut buffer: Vec<u8> = Vec::new();
 
-            if path.is_dir() {
-                queue.push_back((path, remaining_depth - 1));
-            }
-        }
+        fs::File::open(path)
+            .await?
+            .take(limit)
+            .read_to_end(&mut buffer)
+            .await?;
+
+        Ok(buffer)
     }
+}
 
-    Err(io::Error::from(io::ErrorKind::NotFound))
+async fn get_dir(options: GetDir) -> Result<PathBuf, GetDirError> {
+    get_dir_detailed(options).await.map(|found| found.dir)
 }
 
-async fn get_dir_reverse(options: GetDir) -> io::Result<PathBuf> {
-    let GetDir { dir, depth, targets } = options;
+async fn get_dir_detailed(options: GetDir) -> Result<Match, GetDirError> {
+    Walker::new(TokioFs, options)?.first().await
+}
 
-    for (i, ancestor) in dir.ancestors().enumerate() {
-        if i >= depth {
-            break;
-        }
+async fn get_dir_all(options: GetDir) -> Result<Vec<PathBuf>, GetDirError> {
+    Walker::new(TokioFs, options)?.collect().await
+}
 
-        if is_targets_exist(ancestor, &targets) {
-            return Ok(ancestor.to_path_buf());
-        }
-    }
+async fn get_dir_reverse(options: GetDir) -> Result<PathBuf, GetDirError> {
+    get_dir_reverse_detailed(options).await.map(|found| found.dir)
+}
 
-    Err(io::Error::from(io::ErrorKind::NotFound))
+async fn get_dir_reverse_detailed(
+    options: GetDir
+) -> Result<Match, GetDirError> {
+    Ancestors::new(TokioFs, options)?.first().await
+}
+
+async fn get_dir_reverse_bounded(
+    options: GetDir
+) -> Result<Outcome, GetDirError> {
+    Ancestors::new(TokioFs, options)?.outcome().await
+}
+
+async fn get_dir_reverse_outermost(
+    options: GetDir
+) -> Result<PathBuf, GetDirError> {
+    Ancestors::new(TokioFs, options)?.last().await.map(|found| found.dir)
+}
+
+async fn get_dir_nearest(options: GetDir) -> Result<PathBuf, GetDirError> {
+    nearest(&TokioFs, options).await.map(|found| found.dir)
 }
 
 /// Trait for getting directory with tokio.
@@ -80,7 +139,117 @@ pub trait GetDirAsyncExt {
     /// ```
     fn run_async(
         self
-    ) -> impl std::future::Future<Output = io::Result<PathBuf>> + Send;
+    ) -> impl Future<Output = Result<PathBuf, GetDirError>> + Send;
+
+    /// Get directory asynchronously, along with the matched target and entry.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use get_dir::{
+    ///     GetDir,
+    ///     Match,
+    ///     tokio::GetDirAsyncExt,
+    /// };
+    ///
+    /// # async fn example() {
+    /// let found: Match = GetDir::new()
+    ///     .run_detailed_async()
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    fn run_detailed_async(
+        self
+    ) -> impl Future<Output = Result<Match, GetDirError>> + Send;
+
+    /// Get every directory containing any of the targets asynchronously.
+    ///
+    /// The directories are returned in the order of [`GetDir::strategy`]
+    /// without duplicates.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::path::PathBuf;
+    ///
+    /// use get_dir::{
+    ///     GetDir,
+    ///     tokio::GetDirAsyncExt,
+    /// };
+    ///
+    /// # async fn example() {
+    /// let paths: Vec<PathBuf> = GetDir::new()
+    ///     .run_all_async()
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    fn run_all_async(
+        self
+    ) -> impl Future<Output = Result<Vec<PathBuf>, GetDirError>> + Send;
+
+    /// Get a stream over the directories containing any of the targets.
+    ///
+    /// The matches are yielded as the search discovers them, in the order of
+    /// [`GetDir::strategy`], and dropping the stream cancels the search. An
+    /// invalid target is yielded as the only error, and so is a directory the
+    /// search could not start in when nothing is found. A cancelled or timed
+    /// out search yields [`GetDirError::Cancelled`] or
+    /// [`GetDirError::Timeout`] after the matches found so far, then ends.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::pin::pin;
+    ///
+    /// use futures_util::StreamExt as _;
+    /// use get_dir::{
+    ///     GetDir,
+    ///     Match,
+    ///     tokio::GetDirAsyncExt,
+    /// };
+    ///
+    /// # async fn example() {
+    /// let mut stream = pin!(GetDir::new().stream_async());
+    ///
+    /// while let Some(found) = stream.next().await {
+    ///     let found: Match = found.unwrap();
+    /// }
+    /// # }
+    /// ```
+    fn stream_async(
+        self
+    ) -> impl futures_util::Stream<Item = Result<Match, GetDirError>> + Send;
+
+    /// Get a stream over the ancestors containing any of the targets.
+    ///
+    /// The ancestors are yielded from nearest to farthest, and dropping the
+    /// stream cancels the search.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::pin::pin;
+    ///
+    /// use futures_util::StreamExt as _;
+    /// use get_dir::{
+    ///     GetDir,
+    ///     Match,
+    ///     tokio::GetDirAsyncExt,
+    /// };
+    ///
+    /// # async fn example() {
+    /// let mut stream = pin!(GetDir::new().stream_reverse_async());
+    ///
+    /// while let Some(found) = stream.next().await {
+    ///     let found: Match = found.unwrap();
+    /// }
+    /// # }
+    /// ```
+    fn stream_reverse_async(
+        self
+    ) -> impl futures_util::Stream<Item = Result<Match, GetDirError>> + Send;
 
     /// Get directory in reverse asynchronously.
     ///
@@ -103,15 +272,151 @@ pub trait GetDirAsyncExt {
     /// ```
     fn run_reverse_async(
         self
-    ) -> impl std::future::Future<Output = io::Result<PathBuf>> + Send;
+    ) -> impl Future<Output = Result<PathBuf, GetDirError>> + Send;
+
+    /// Get directory in reverse asynchronously, along with the matched target
+    /// and entry.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use get_dir::{
+    ///     GetDir,
+    ///     Match,
+    ///     tokio::GetDirAsyncExt,
+    /// };
+    ///
+    /// # async fn example() {
+    /// let found: Match = GetDir::new()
+    ///     .run_reverse_detailed_async()
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    fn run_reverse_detailed_async(
+        self
+    ) -> impl Future<Output = Result<Match, GetDirError>> + Send;
+
+    /// Get directory in reverse asynchronously, or how the search ended
+    /// without one.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use get_dir::{
+    ///     GetDir,
+    ///     Outcome,
+    ///     tokio::GetDirAsyncExt,
+    /// };
+    ///
+    /// # async fn example() {
+    /// let outcome: Outcome = GetDir::new()
+    ///     .run_reverse_bounded_async()
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    fn run_reverse_bounded_async(
+        self
+    ) -> impl Future<Output = Result<Outcome, GetDirError>> + Send;
+
+    /// Get the farthest ancestor containing any of the targets asynchronously.
+    ///
+    /// The `depth` is the maximum number of ancestors examined.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::path::PathBuf;
+    ///
+    /// use get_dir::{
+    ///     GetDir,
+    ///     tokio::GetDirAsyncExt,
+    /// };
+    ///
+    /// # async fn example() {
+    /// let path: PathBuf = GetDir::new()
+    ///     .run_reverse_outermost_async()
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    fn run_reverse_outermost_async(
+        self
+    ) -> impl Future<Output = Result<PathBuf, GetDirError>> + Send;
+
+    /// Get the nearest directory containing any of the targets
+    /// asynchronously.
+    ///
+    /// The ancestors and the descendants are searched together, limited by
+    /// the `up_depth` and the `down_depth` instead of the `depth`.
+    ///
+    /// ## Example
+    ///
+    /// ```no_run
+    /// use std::path::PathBuf;
+    ///
+    /// use get_dir::{
+    ///     GetDir,
+    ///     tokio::GetDirAsyncExt,
+    /// };
+    ///
+    /// # async fn example() {
+    /// let path: PathBuf = GetDir::new()
+    ///     .run_nearest_async()
+    ///     .await
+    ///     .unwrap();
+    /// # }
+    /// ```
+    fn run_nearest_async(
+        self
+    ) -> impl Future<Output = Result<PathBuf, GetDirError>> + Send;
 }
 
 impl GetDirAsyncExt for GetDir {
-    async fn run_async(self) -> io::Result<PathBuf> {
+    async fn run_async(self) -> Result<PathBuf, GetDirError> {
         get_dir(self).await
     }
 
-    async fn run_reverse_async(self) -> io::Result<PathBuf> {
+    async fn run_detailed_async(self) -> Result<Match, GetDirError> {
+        get_dir_detailed(self).await
+    }
+
+    async fn run_all_async(self) -> Result<Vec<PathBuf>, GetDirError> {
+        get_dir_all(self).await
+    }
+
+    fn stream_async(
+        self
+    ) -> impl futures_util::Stream<Item = Result<Match, GetDirError>> + Send
+    {
+        stream(Walker::new(TokioFs, self).map(Search::Forward))
+    }
+
+    fn stream_reverse_async(
+        self
+    ) -> impl futures_util::Stream<Item = Result<Match, GetDirError>> + Send
+    {
+        stream(Ancestors::new(TokioFs, self).map(Search::Reverse))
+    }
+
+    async fn run_reverse_async(self) -> Result<PathBuf, GetDirError> {
         get_dir_reverse(self).await
     }
+
+    async fn run_reverse_detailed_async(self) -> Result<Match, GetDirError> {
+        get_dir_reverse_detailed(self).await
+    }
+
+    async fn run_reverse_bounded_async(self) -> Result<Outcome, GetDirError> {
+        get_dir_reverse_bounded(self).await
+    }
+
+    async fn run_reverse_outermost_async(self) -> Result<PathBuf, GetDirError> {
+        get_dir_reverse_outermost(self).await
+    }
+
+    async fn run_nearest_async(self) -> Result<PathBuf, GetDirError> {
+        get_dir_nearest(self).await
+    }
 }
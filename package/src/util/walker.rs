@@ -0,0 +1,985 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    io,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Instant,
+};
+
+use crate::{
+    structs::{
+        boundary::Boundary, cancel_token::CancelToken, direction::Direction,
+        error::GetDirError, follow_links::FollowLinks, matched::Match,
+        outcome::Outcome, strategy::Strategy, target::Target,
+    },
+    util::{
+        GetDir,
+        file_system::{Entry, Fs, Identity, device, identity, is_dir_entry},
+        filter::Filter,
+        matcher::{Matcher, compile, find_targets, is_targets_exist},
+    },
+};
+
+#[cfg(feature = "ignore")]
+use crate::util::ignore_files::{Excludes, IgnoreFiles};
+
+/// Filter of the directories on the same file system as the root.
+pub(crate) struct SameFileSystem {
+    root: Option<PathBuf>,
+    /// The device of the root, read on the first check.
+    device: Option<Option<u64>>,
+}
+
+impl SameFileSystem {
+    pub(crate) fn new(
+        enabled: bool,
+        root: &Path,
+    ) -> Self {
+        Self { root: enabled.then(|| root.to_path_buf()), device: None }
+    }
+
+    /// Check whether the path is on the same device as the root.
+    ///
+    /// It is always `true` if the filter is disabled.
+    pub(crate) async fn contains<F: Fs>(
+        &mut self,
+        fs: &F,
+        path: &Path,
+    ) -> bool {
+        let Some(root) = &self.root else {
+            return true;
+        };
+
+        let root: Option<u64> = match self.device {
+            | Some(device) => device,
+            | None => *self.device.insert(device(fs, root).await),
+        };
+
+        device(fs, path).await == root
+    }
+}
+
+/// Conditions that stop a search before it ends.
+struct Interrupt {
+    deadline: Option<Instant>,
+    cancel_token: Option<CancelToken>,
+}
+
+impl Interrupt {
+    /// Start the timeout of the search.
+    fn new(options: &GetDir) -> Self {
+        Self {
+            // A timeout too long to represent never expires.
+            deadline: options
+                .timeout
+                .and_then(|timeout| Instant::now().checked_add(timeout)),
+            cancel_token: options.cancel_token.clone(),
+        }
+    }
+
+    /// Check whether the search must stop before the next directory.
+    fn check(&self) -> Option<GetDirError> {
+        if self.cancel_token.as_ref().is_some_and(CancelToken::is_cancelled) {
+            return Some(GetDirError::Cancelled);
+        }
+
+        if self.deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+            return Some(GetDirError::Timeout);
+        }
+
+        None
+    }
+}
+
+/// Rules of the directories a search descends into.
+struct Descent {
+    filter: Filter,
+    skip_hidden: bool,
+    follow_links: FollowLinks,
+    same_file_system: SameFileSystem,
+    #[cfg(feature = "ignore")]
+    respect_ignore_files: bool,
+    #[cfg(feature = "ignore")]
+    excludes: Excludes,
+}
+
+impl Descent {
+    fn new(options: &GetDir) -> Result<Self, GetDirError> {
+        Ok(Self {
+            filter: Filter::new(&options.exclude, &options.include_only)
+                .map_err(GetDirError::invalid)?,
+            skip_hidden: options.skip_hidden,
+            follow_links: options.follow_links,
+            same_file_system: SameFileSystem::new(
+                options.same_file_system,
+                &options.dir,
+            ),
+            #[cfg(feature = "ignore")]
+            respect_ignore_files: options.respect_ignore_files,
+            #[cfg(feature = "ignore")]
+            excludes: Excludes::default(),
+        })
+    }
+
+    /// Get the ignore rules that apply to the entries of the directory, and
+    /// remove the ignored entries.
+    ///
+    /// The rules of the base directory the search descends from are read
+    /// from its repository, and those of the others from their parent.
+    #[cfg(feature = "ignore")]
+    async fn ignore<F: Fs>(
+        &mut self,
+        fs: &F,
+        dir: &Path,
+        parent: Option<Arc<IgnoreFiles>>,
+        is_base: bool,
+        entries: &mut Vec<Entry>,
+    ) -> Option<Arc<IgnoreFiles>> {
+        if !self.respect_ignore_files {
+            return None;
+        }
+
+        let parent: Option<Arc<IgnoreFiles>> = match is_base {
+            | true => IgnoreFiles::root(fs, dir, &mut self.excludes).await,
+            | false => parent,
+        };
+
+        let ignore: Option<Arc<IgnoreFiles>> =
+            IgnoreFiles::child(fs, parent, dir).await;
+
+        if let Some(ignore) = &ignore {
+            entries.retain(|entry| !ignore.is_ignored(&entry.path));
+        }
+
+        ignore
+    }
+
+    /// Check whether the directory is searched.
+    ///
+    /// The patterns are matched relative to the base directory the search
+    /// descends from.
+    fn is_included(
+        &self,
+        base: &Path,
+        dir: &Path,
+    ) -> bool {
+        self.filter.is_included(dir.strip_prefix(base).unwrap_or(dir))
+    }
+
+    /// Get the subdirectories among the entries to descend into.
+    async fn children<F: Fs>(
+        &mut self,
+        fs: &F,
+        base: &Path,
+        entries: Vec<Entry>,
+    ) -> Vec<PathBuf> {
+        let mut children: Vec<PathBuf> = Vec::new();
+
+        for entry in entries {
+            if self.skip_hidden
+                && entry.name.as_encoded_bytes().starts_with(b".")
+            {
+                continue;
+            }
+
+            let is_dir: bool = match self.follow_links {
+                | FollowLinks::Always => is_dir_entry(fs, &entry).await,
+                | FollowLinks::Never | FollowLinks::OnlyRoot => {
+                    entry.file_type.is_dir()
+                },
+            };
+
+            let relative: &Path =
+                entry.path.strip_prefix(base).unwrap_or(&entry.path);
+
+            if !is_dir || !self.filter.is_traversed(relative) {
+                continue;
+            }
+
+            if !self.same_file_system.contains(fs, &entry.path).await {
+                continue;
+            }
+
+            children.push(entry.path);
+        }
+
+        children
+    }
+}
+
+/// The identities of a directory and its ancestors in the forward search.
+struct Lineage {
+    identity: Identity,
+    parent: Option<Arc<Lineage>>,
+}
+
+impl Lineage {
+    fn contains(
+        &self,
+        identity: &Identity,
+    ) -> bool {
+        let mut current: Option<&Self> = Some(self);
+
+        while let Some(lineage) = current {
+            if lineage.identity == *identity {
+                return true;
+            }
+
+            current = lineage.parent.as_deref();
+        }
+
+        false
+    }
+}
+
+/// A directory waiting in the frontier of the forward search.
+struct Node {
+    dir: PathBuf,
+    /// The distance from the root.
+    level: usize,
+    /// The identities of the directories on the path to the directory.
+    lineage: Option<Arc<Lineage>>,
+    /// The ignore rules that apply to the directory.
+    #[cfg(feature = "ignore")]
+    ignore: Option<Arc<IgnoreFiles>>,
+}
+
+/// Forward search over the directory tree.
+///
+/// The search advances only when [`Walker::next`] is polled, so it can back
+/// both the single-shot and the incremental APIs.
+pub(crate) struct Walker<F: Fs> {
+    fs: F,
+    matchers: Vec<Matcher>,
+    strategy: Strategy,
+    descent: Descent,
+    interrupt: Interrupt,
+    root: PathBuf,
+    depth: usize,
+    frontier: VecDeque<Node>,
+    /// The last matched directory, expanded on the next call.
+    pending: Option<Node>,
+    /// The depth limit of the current iterative deepening pass.
+    limit: usize,
+    /// Whether the current iterative deepening pass reached its limit.
+    reached: bool,
+    /// The directories returned so far.
+    found: HashSet<Identity>,
+    /// Whether any directory was returned.
+    matched: bool,
+    /// The error that stopped the search before it ended.
+    stopped: Option<GetDirError>,
+    /// The number of directories checked.
+    visits: usize,
+    /// The greatest level checked.
+    deepest: usize,
+    /// The number of directories that could not be read.
+    skipped: usize,
+}
+
+impl<F: Fs> Walker<F> {
+    pub(crate) fn new(
+        fs: F,
+        options: GetDir,
+    ) -> Result<Self, GetDirError> {
+        let descent: Descent = Descent::new(&options)?;
+        let interrupt: Interrupt = Interrupt::new(&options);
+
+        let GetDir { dir, depth, targets, strategy, .. } = options;
+
+        let matchers: Vec<Matcher> =
+            compile(&targets).map_err(GetDirError::invalid)?;
+
+        let mut frontier: VecDeque<Node> = VecDeque::new();
+
+        if depth > 0 {
+            frontier.push_back(Node {
+                dir: dir.clone(),
+                level: 0,
+                lineage: None,
+                #[cfg(feature = "ignore")]
+                ignore: None,
+            });
+        }
+
+        Ok(Self {
+            fs,
+            matchers,
+            strategy,
+            descent,
+            interrupt,
+            root: dir,
+            depth,
+            frontier,
+            pending: None,
+            limit: 1,
+            reached: false,
+            found: HashSet::new(),
+            matched: false,
+            stopped: None,
+            visits: 0,
+            deepest: 0,
+            skipped: 0,
+        })
+    }
+
+    fn pop(&mut self) -> Option<Node> {
+        match self.strategy {
+            | Strategy::Bfs => self.frontier.pop_front(),
+            | Strategy::Dfs | Strategy::IterativeDeepening => {
+                self.frontier.pop_back()
+            },
+        }
+    }
+
+    async fn expand(
+        &mut self,
+        node: Node,
+    ) {
+        let Node {
+            dir,
+            level,
+            lineage,
+            #[cfg(feature = "ignore")]
+            ignore,
+        } = node;
+
+        let limit: usize = match self.strategy {
+            | Strategy::IterativeDeepening => self.limit,
+            | Strategy::Bfs | Strategy::Dfs => self.depth,
+        };
+
+        if level + 1 >= limit {
+            return;
+        }
+
+        if level == 0
+            && self.descent.follow_links == FollowLinks::Never
+            && self
+                .fs
+                .symlink_metadata(&dir)
+                .await
+                .is_ok_and(|metadata| metadata.is_symlink())
+        {
+            return;
+        }
+
+        let Ok(entries) = self.fs.read_dir(&dir).await else {
+            // Upper levels are expanded again by each iterative deepening
+            // pass, so a directory is only counted on its first expansion.
+            if self.strategy != Strategy::IterativeDeepening
+                || level + 2 == limit
+            {
+                self.skipped += 1;
+            }
+
+            return;
+        };
+
+        #[cfg(feature = "ignore")]
+        let mut entries: Vec<Entry> = entries;
+
+        #[cfg(feature = "ignore")]
+        let ignore: Option<Arc<IgnoreFiles>> = self
+            .descent
+            .ignore(&self.fs, &dir, ignore, level == 0, &mut entries)
+            .await;
+
+        let children: Vec<Node> = self
+            .descent
+            .children(&self.fs, &self.root, entries)
+            .await
+            .into_iter()
+            .map(|dir| Node {
+                dir,
+                level: level + 1,
+                lineage: lineage.clone(),
+                #[cfg(feature = "ignore")]
+                ignore: ignore.clone(),
+            })
+            .collect();
+
+        match self.strategy {
+            | Strategy::Bfs => self.frontier.extend(children),
+            | Strategy::Dfs | Strategy::IterativeDeepening => {
+                self.frontier.extend(children.into_iter().rev())
+            },
+        }
+    }
+
+    /// Start the next iterative deepening pass if the last one was not the
+    /// final one.
+    fn deepen(&mut self) -> bool {
+        if self.strategy != Strategy::IterativeDeepening
+            || !self.reached
+            || self.limit >= self.depth
+        {
+            return false;
+        }
+
+        self.limit += 1;
+        self.reached = false;
+        self.frontier.push_back(Node {
+            dir: self.root.clone(),
+            level: 0,
+            lineage: None,
+            #[cfg(feature = "ignore")]
+            ignore: None,
+        });
+
+        true
+    }
+
+    /// Get the next directory containing any of the targets.
+    ///
+    /// Directories reached through different paths are returned once.
+    pub(crate) async fn next(&mut self) -> Option<Match> {
+        if let Some(node) = self.pending.take() {
+            self.expand(node).await;
+        }
+
+        loop {
+            while let Some(mut node) = self.pop() {
+                if let Some(error) = self.interrupt.check() {
+                    self.stopped = Some(error);
+                    self.frontier.clear();
+
+                    return None;
+                }
+
+                let identity: Option<Identity> =
+                    identity(&self.fs, &node.dir).await;
+
+                // A directory that is its own ancestor through a symlink is a
+                // cycle, so it is skipped.
+                if let Some(identity) = &identity {
+                    if node
+                        .lineage
+                        .as_ref()
+                        .is_some_and(|l| l.contains(identity))
+                    {
+                        continue;
+                    }
+
+                    node.lineage = Some(Arc::new(Lineage {
+                        identity: identity.clone(),
+                        parent: node.lineage.take(),
+                    }));
+                }
+
+                self.visits += 1;
+                self.deepest = self.deepest.max(node.level);
+
+                if self.strategy == Strategy::IterativeDeepening {
+                    // Upper levels were checked by the previous passes.
+                    if node.level + 1 < self.limit {
+                        self.expand(node).await;
+                        continue;
+                    }
+
+                    self.reached = true;
+                }
+
+                let matched: Option<(usize, PathBuf)> =
+                    if self.descent.is_included(&self.root, &node.dir) {
+                        find_targets(&self.fs, &node.dir, &self.matchers).await
+                    } else {
+                        None
+                    };
+
+                if let Some((target, path)) = matched {
+                    if identity
+                        .is_none_or(|identity| self.found.insert(identity))
+                    {
+                        let found: Match = Match {
+                            dir: node.dir.clone(),
+                            target,
+                            path,
+                            depth: node.level,
+                            direction: Direction::Forward,
+                        };
+
+                        self.pending = Some(node);
+                        self.matched = true;
+
+                        return Some(found);
+                    }
+                }
+
+                self.expand(node).await;
+            }
+
+            if !self.deepen() {
+                return None;
+            }
+        }
+    }
+
+    /// Get the error of the search once it ends.
+    ///
+    /// It is the error that stopped the search, or the directory the search
+    /// could not start in, if nothing was found.
+    pub(crate) async fn error(&mut self) -> Option<GetDirError> {
+        if let Some(error) = self.stopped.take() {
+            return Some(error);
+        }
+
+        match self.matched {
+            | true => None,
+            | false => start_error(&self.fs, &self.root).await,
+        }
+    }
+
+    /// Get the first directory containing any of the targets.
+    pub(crate) async fn first(mut self) -> Result<Match, GetDirError> {
+        if let Some(found) = self.next().await {
+            return Ok(found);
+        }
+
+        Err(self.error().await.unwrap_or(GetDirError::NotFound {
+            visited: self.visits,
+            depth: self.deepest,
+            skipped: self.skipped,
+        }))
+    }
+
+    /// Get every directory containing any of the targets.
+    pub(crate) async fn collect(mut self) -> Result<Vec<PathBuf>, GetDirError> {
+        let mut dirs: Vec<PathBuf> = Vec::new();
+
+        while let Some(found) = self.next().await {
+            dirs.push(found.dir);
+        }
+
+        match self.error().await {
+            | Some(error) => Err(error),
+            | None => Ok(dirs),
+        }
+    }
+}
+
+/// Compiled boundaries of the search upward.
+pub(crate) struct Boundaries {
+    paths: Vec<PathBuf>,
+    matchers: Vec<Matcher>,
+}
+
+impl Boundaries {
+    pub(crate) fn new(boundaries: &[Boundary]) -> io::Result<Self> {
+        let mut paths: Vec<PathBuf> = Vec::new();
+        let mut targets: Vec<Target> = Vec::new();
+
+        for boundary in boundaries {
+            match boundary {
+                | Boundary::Path(path) => paths.push(path.clone()),
+                | Boundary::Target(target) => targets.push(target.clone()),
+            }
+        }
+
+        Ok(Self { paths, matchers: compile(&targets)? })
+    }
+
+    /// Check whether the search upward must stop at the directory.
+    pub(crate) async fn is_boundary<F: Fs>(
+        &self,
+        fs: &F,
+        dir: &Path,
+    ) -> bool {
+        if self.paths.iter().any(|path| path == dir) {
+            return true;
+        }
+
+        if !self.paths.is_empty() {
+            if let Ok(dir) = fs.canonicalize(dir).await {
+                for path in &self.paths {
+                    let path: io::Result<PathBuf> = fs.canonicalize(path).await;
+
+                    if path.is_ok_and(|path| path == dir) {
+                        return true;
+                    }
+                }
+            }
+        }
+
+        is_targets_exist(fs, dir, &self.matchers).await
+    }
+}
+
+/// Reverse search over the ancestors, from nearest to farthest.
+pub(crate) struct Ancestors<F: Fs> {
+    fs: F,
+    matchers: Vec<Matcher>,
+    boundaries: Boundaries,
+    same_file_system: SameFileSystem,
+    root: PathBuf,
+    depth: usize,
+    interrupt: Interrupt,
+    current: Option<PathBuf>,
+    distance: usize,
+    end: Outcome,
+    /// Whether any ancestor was returned.
+    matched: bool,
+    /// The error that stopped the search before it ended.
+    stopped: Option<GetDirError>,
+}
+
+impl<F: Fs> Ancestors<F> {
+    pub(crate) fn new(
+        fs: F,
+        options: GetDir,
+    ) -> Result<Self, GetDirError> {
+        let interrupt: Interrupt = Interrupt::new(&options);
+
+        let GetDir {
+            dir, depth, targets, boundaries, same_file_system, ..
+        } = options;
+
+        let matchers: Vec<Matcher> =
+            compile(&targets).map_err(GetDirError::invalid)?;
+        let boundaries: Boundaries =
+            Boundaries::new(&boundaries).map_err(GetDirError::invalid)?;
+
+        Ok(Self {
+            fs,
+            matchers,
+            boundaries,
+            same_file_system: SameFileSystem::new(same_file_system, &dir),
+            root: dir.clone(),
+            depth,
+            interrupt,
+            current: Some(dir),
+            distance: 0,
+            end: Outcome::Root,
+            matched: false,
+            stopped: None,
+        })
+    }
+
+    /// Get the next ancestor containing any of the targets.
+    pub(crate) async fn next(&mut self) -> Option<Match> {
+        while let Some(dir) = self.current.take() {
+            if self.distance >= self.depth {
+                self.end = Outcome::Depth;
+                return None;
+            }
+
+            if let Some(error) = self.interrupt.check() {
+                self.stopped = Some(error);
+                return None;
+            }
+
+            let distance: usize = self.distance;
+
+            self.distance += 1;
+
+            // A mount point stops the search like a boundary.
+            let stop: bool = self.boundaries.is_boundary(&self.fs, &dir).await
+                || match dir.parent() {
+                    | Some(parent) => {
+                        !self.same_file_system.contains(&self.fs, parent).await
+                    },
+                    | None => false,
+                };
+
+            if stop {
+                self.end = Outcome::Boundary(dir.clone());
+            } else {
+                self.current = dir.parent().map(Path::to_path_buf);
+            }
+
+            if let Some((target, path)) =
+                find_targets(&self.fs, &dir, &self.matchers).await
+            {
+                let direction: Direction = match distance {
+                    | 0 => Direction::Forward,
+                    | _ => Direction::Reverse,
+                };
+
+                self.matched = true;
+
+                return Some(Match {
+                    dir,
+                    target,
+                    path,
+                    depth: distance,
+                    direction,
+                });
+            }
+        }
+
+        None
+    }
+
+    /// Get the error of the search once it ends.
+    ///
+    /// It is the error that stopped the search, or the directory the search
+    /// could not start in, if nothing was found.
+    pub(crate) async fn error(&mut self) -> Option<GetDirError> {
+        if let Some(error) = self.stopped.take() {
+            return Some(error);
+        }
+
+        match self.matched {
+            | true => None,
+            | false => start_error(&self.fs, &self.root).await,
+        }
+    }
+
+    fn not_found(&self) -> GetDirError {
+        GetDirError::NotFound {
+            visited: self.distance,
+            depth: self.distance.saturating_sub(1),
+            skipped: 0,
+        }
+    }
+
+    /// Get the nearest ancestor containing any of the targets.
+    pub(crate) async fn first(mut self) -> Result<Match, GetDirError> {
+        if let Some(found) = self.next().await {
+            return Ok(found);
+        }
+
+        Err(self.error().await.unwrap_or_else(|| self.not_found()))
+    }
+
+    /// Get the nearest ancestor containing any of the targets, or how the
+    /// search ended without one.
+    pub(crate) async fn outcome(mut self) -> Result<Outcome, GetDirError> {
+        if let Some(found) = self.next().await {
+            return Ok(Outcome::Found(found.dir));
+        }
+
+        match self.error().await {
+            | Some(error) => Err(error),
+            | None => Ok(self.end),
+        }
+    }
+
+    /// Get the farthest ancestor containing any of the targets.
+    pub(crate) async fn last(mut self) -> Result<Match, GetDirError> {
+        let mut last: Option<Match> = None;
+
+        while let Some(found) = self.next().await {
+            last = Some(found);
+        }
+
+        if let Some(error) = self.error().await {
+            return Err(error);
+        }
+
+        last.ok_or_else(|| self.not_found())
+    }
+}
+
+/// A directory waiting in the queue of the nearest search.
+struct Neighbour {
+    dir: PathBuf,
+    /// The number of levels up from the directory the search starts in.
+    up: usize,
+    /// The number of levels down from the ancestor it was reached from.
+    down: usize,
+    /// The ignore rules that apply to the directory.
+    #[cfg(feature = "ignore")]
+    ignore: Option<Arc<IgnoreFiles>>,
+}
+
+/// Get the nearest directory containing any of the targets.
+///
+/// The tree is searched in BFS order from the directory, where the neighbours
+/// of an ancestor are its parent and its children, so the siblings of the
+/// ancestors are reached as well. It never goes up past a boundary, and the
+/// descendants of each ancestor are filtered relative to that ancestor.
+pub(crate) async fn nearest<F: Fs>(
+    fs: &F,
+    options: GetDir,
+) -> Result<Match, GetDirError> {
+    let mut descent: Descent = Descent::new(&options)?;
+    let interrupt: Interrupt = Interrupt::new(&options);
+
+    let GetDir { dir, targets, boundaries, up_depth, down_depth, .. } = options;
+
+    let matchers: Vec<Matcher> =
+        compile(&targets).map_err(GetDirError::invalid)?;
+    let boundaries: Boundaries =
+        Boundaries::new(&boundaries).map_err(GetDirError::invalid)?;
+
+    let mut queue: VecDeque<Neighbour> = VecDeque::new();
+    let mut visited: HashSet<Identity> = HashSet::new();
+    let mut visits: usize = 0;
+    let mut deepest: usize = 0;
+    let mut skipped: usize = 0;
+
+    if up_depth > 0 && down_depth > 0 {
+        queue.push_back(Neighbour {
+            dir: dir.clone(),
+            up: 0,
+            down: 0,
+            #[cfg(feature = "ignore")]
+            ignore: None,
+        });
+    }
+
+    while let Some(neighbour) = queue.pop_front() {
+        if let Some(error) = interrupt.check() {
+            return Err(error);
+        }
+
+        let Neighbour {
+            dir,
+            up,
+            down,
+            #[cfg(feature = "ignore")]
+            ignore,
+        } = neighbour;
+
+        if let Some(identity) = identity(fs, &dir).await {
+            if !visited.insert(identity) {
+                continue;
+            }
+        }
+
+        // The ancestor the directory was reached from.
+        let base: &Path = dir.ancestors().nth(down).unwrap_or(&dir);
+
+        visits += 1;
+        deepest = deepest.max(up + down);
+
+        let matched: Option<(usize, PathBuf)> =
+            if down == 0 || descent.is_included(base, &dir) {
+                find_targets(fs, &dir, &matchers).await
+            } else {
+                None
+            };
+
+        if let Some((target, path)) = matched {
+            let direction: Direction = match up {
+                | 0 => Direction::Forward,
+                | _ => Direction::Reverse,
+            };
+
+            return Ok(Match {
+                dir,
+                target,
+                path,
+                depth: up + down,
+                direction,
+            });
+        }
+
+        if down + 1 < down_depth {
+            let entries: Vec<Entry> = match fs.read_dir(&dir).await {
+                | Ok(entries) => entries,
+                | Err(_) => {
+                    skipped += 1;
+                    Vec::new()
+                },
+            };
+
+            #[cfg(feature = "ignore")]
+            let mut entries: Vec<Entry> = entries;
+
+            #[cfg(feature = "ignore")]
+            let ignore: Option<Arc<IgnoreFiles>> =
+                descent.ignore(fs, &dir, ignore, down == 0, &mut entries).await;
+
+            for child in descent.children(fs, base, entries).await {
+                queue.push_back(Neighbour {
+                    dir: child,
+                    up,
+                    down: down + 1,
+                    #[cfg(feature = "ignore")]
+                    ignore: ignore.clone(),
+                });
+            }
+        }
+
+        if down == 0
+            && up + 1 < up_depth
+            && !boundaries.is_boundary(fs, &dir).await
+        {
+            if let Some(parent) = dir.parent() {
+                if descent.same_file_system.contains(fs, parent).await {
+                    queue.push_back(Neighbour {
+                        dir: parent.to_path_buf(),
+                        up: up + 1,
+                        down: 0,
+                        #[cfg(feature = "ignore")]
+                        ignore: None,
+                    });
+                }
+            }
+        }
+    }
+
+    Err(start_error(fs, &dir).await.unwrap_or(GetDirError::NotFound {
+        visited: visits,
+        depth: deepest,
+        skipped,
+    }))
+}
+
+/// Get the error of the directory a search starts in, if it cannot be read.
+///
+/// It is reported instead of [`GetDirError::NotFound`] when nothing is found,
+/// so every search tells a missing or unreadable directory apart.
+async fn start_error<F: Fs>(
+    fs: &F,
+    dir: &Path,
+) -> Option<GetDirError> {
+    match fs.read_dir(dir).await {
+        | Ok(_) => None,
+        | Err(error) => Some(GetDirError::io(dir.to_path_buf(), error)),
+    }
+}
+
+/// Search in either direction.
+pub(crate) enum Search<F: Fs> {
+    Forward(Walker<F>),
+    Reverse(Ancestors<F>),
+    /// The search has ended.
+    Done,
+}
+
+impl<F: Fs> Search<F> {
+    /// Get the next match, then the error of the search once it ends.
+    pub(crate) async fn next(&mut self) -> Option<Result<Match, GetDirError>> {
+        let found: Option<Match> = match self {
+            | Self::Forward(walker) => walker.next().await,
+            | Self::Reverse(ancestors) => ancestors.next().await,
+            | Self::Done => return None,
+        };
+
+        if let Some(found) = found {
+            return Some(Ok(found));
+        }
+
+        let error: Option<GetDirError> = match self {
+            | Self::Forward(walker) => walker.error().await,
+            | Self::Reverse(ancestors) => ancestors.error().await,
+            | Self::Done => None,
+        };
+
+        *self = Self::Done;
+
+        error.map(Err)
+    }
+}
+
+/// Get a stream over the directories containing any of the targets.
+///
+/// An invalid target is yielded as the only error, and so is a directory the
+/// search could not start in when nothing is found. A stopped search yields
+/// its error after the matches found so far.
+#[cfg(any(feature = "async_std", feature = "smol", feature = "tokio"))]
+pub(crate) fn stream<F: Fs + Send>(
+    search: Result<Search<F>, GetDirError>
+) -> impl futures_util::Stream<Item = Result<Match, GetDirError>> + Send {
+    futures_util::stream::unfold(search.map_err(Some), |state| async move {
+        match state {
+            | Ok(mut search) => {
+                search.next().await.map(|found| (found, Ok(search)))
+            },
+            | Err(error) => error.map(|e| (Err(e), Err(None))),
+        }
+    })
+}
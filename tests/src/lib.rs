@@ -4,11 +4,26 @@ pub mod smol;
 
 pub mod tokio;
 
+#[cfg(test)]
+mod temp_dir;
+
 #[cfg(test)]
 mod tests {
-    use std::{env::current_dir, fs::read_to_string, path::PathBuf};
+    use std::{
+        env::current_dir,
+        fs::read_to_string,
+        io,
+        path::PathBuf,
+        time::{Duration, SystemTime},
+    };
 
-    use get_dir::{DirTarget, FileTarget, GetDir, Target};
+    use crate::temp_dir::TempDir;
+
+    use get_dir::{
+        Boundary, CancelToken, Case, CustomTarget, DirTarget, Direction,
+        EntryKind, EntryTarget, FileTarget, FollowLinks, Format, GetDir,
+        GetDirError, Match, Outcome, Strategy, Structured, Target,
+    };
 
     #[test]
     fn test_get_dir_by_target_dir() {
@@ -72,6 +87,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::redundant_pattern_matching)]
     fn test_get_dir_with_depth_limit() {
         let dir: PathBuf = current_dir().unwrap();
         let target: Target = Target::File(FileTarget::new("lib.rs"));
@@ -88,6 +104,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::redundant_pattern_matching)]
     fn test_get_dir_reverse_with_depth_limit() {
         let dir: PathBuf = current_dir().unwrap();
         let target: Target = Target::File(FileTarget::new("Cargo.lock"));
@@ -107,4 +124,1195 @@ mod tests {
             panic!("Should succeed");
         }
     }
+
+    #[test]
+    fn test_get_dir_by_target_file_glob() {
+        let dir: PathBuf = GetDir::new()
+            .dir(current_dir().unwrap().join("assets").join("glob"))
+            .target(Target::File(FileTarget::glob("*.sln")))
+            .run()
+            .unwrap();
+
+        assert!(dir.ends_with("solution"));
+    }
+
+    #[test]
+    fn test_get_dir_by_target_dir_glob() {
+        let dir: PathBuf = GetDir::new()
+            .dir(current_dir().unwrap().join("assets"))
+            .target(Target::Dir(DirTarget::glob("build_*")))
+            .run()
+            .unwrap();
+
+        assert!(dir.ends_with("glob"));
+    }
+
+    #[test]
+    fn test_get_dir_by_target_recursive_glob() {
+        let dir: PathBuf = GetDir::new()
+            .dir(current_dir().unwrap().join("assets").join("glob"))
+            .target(Target::File(FileTarget::glob("**/deep/*.txt")))
+            .run()
+            .unwrap();
+
+        assert!(dir.ends_with("glob"));
+    }
+
+    #[test]
+    fn test_get_dir_with_invalid_glob() {
+        let result: Result<PathBuf, GetDirError> =
+            GetDir::new().target(Target::File(FileTarget::glob("["))).run();
+
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn test_get_dir_by_target_file_regex() {
+        let dir: PathBuf = GetDir::new()
+            .dir(current_dir().unwrap().join("assets").join("regex"))
+            .target(Target::File(FileTarget::regex(r"^Dockerfile(\..+)?$")))
+            .run()
+            .unwrap();
+
+        assert!(dir.ends_with("app"));
+    }
+
+    #[test]
+    fn test_get_dir_by_target_dir_regex_in_reverse() {
+        let dir: PathBuf = GetDir::new()
+            .dir(
+                current_dir().unwrap().join("assets").join("regex").join("app"),
+            )
+            .target(Target::Dir(DirTarget::regex(r"^ass.ts$")))
+            .run_reverse()
+            .unwrap();
+
+        assert!(dir.ends_with("tests"));
+    }
+
+    #[test]
+    fn test_get_dir_by_target_custom() {
+        let dir: PathBuf = GetDir::new()
+            .target(Target::Custom(CustomTarget::new(|dir| {
+                dir.join("Cargo.toml").is_file() && !dir.join("src").is_dir()
+            })))
+            .run_reverse()
+            .unwrap();
+
+        let content: String = read_to_string(dir.join("Cargo.toml")).unwrap();
+
+        assert!(content.contains("[workspace.dependencies]"));
+    }
+
+    #[test]
+    fn test_get_dir_by_target_all() {
+        let dir: PathBuf = GetDir::new()
+            .dir(current_dir().unwrap().join("src"))
+            .target(Target::All(vec![
+                Target::File(FileTarget::new("Cargo.toml")),
+                Target::Dir(DirTarget::new("assets")),
+            ]))
+            .run_reverse()
+            .unwrap();
+
+        let content: String = read_to_string(dir.join("Cargo.toml")).unwrap();
+
+        assert!(content.contains("get_dir = { workspace = true }"));
+    }
+
+    #[test]
+    fn test_get_dir_by_target_not() {
+        let dir: PathBuf = GetDir::new()
+            .target(Target::All(vec![
+                Target::File(FileTarget::new("Cargo.toml")),
+                Target::Not(Box::new(Target::Any(vec![
+                    Target::Dir(DirTarget::new("src")),
+                    Target::Dir(DirTarget::new("benches")),
+                ]))),
+            ]))
+            .run_reverse()
+            .unwrap();
+
+        let content: String = read_to_string(dir.join("Cargo.toml")).unwrap();
+
+        assert!(content.contains("[workspace.dependencies]"));
+    }
+
+    #[test]
+    fn test_get_dir_by_target_file_content() {
+        let dir: PathBuf = GetDir::new()
+            .target(Target::File(
+                FileTarget::new("Cargo.toml").contains("[workspace]"),
+            ))
+            .run_reverse()
+            .unwrap();
+
+        let content: String = read_to_string(dir.join("Cargo.toml")).unwrap();
+
+        assert!(content.contains("[workspace.dependencies]"));
+    }
+
+    #[test]
+    fn test_get_dir_by_target_file_content_regex() {
+        let dir: PathBuf = GetDir::new()
+            .dir(current_dir().unwrap().join("..").join("package"))
+            .target(Target::File(
+                FileTarget::glob("*.rs").content_regex(r"(?m)^//! # Get Dir$"),
+            ))
+            .run()
+            .unwrap();
+
+        assert!(dir.ends_with("src"));
+    }
+
+    #[test]
+    fn test_get_dir_by_target_file_content_with_read_limit() {
+        let result: Result<PathBuf, GetDirError> = GetDir::new()
+            .target(Target::File(
+                FileTarget::new("Cargo.toml")
+                    .contains("[workspace]")
+                    .read_limit(5),
+            ))
+            .run_reverse();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_get_dir_by_target_file_json_key() {
+        let dir: PathBuf = GetDir::new()
+            .dir(current_dir().unwrap().join("assets").join("structured"))
+            .target(Target::File(
+                FileTarget::new("package.json")
+                    .structured(Structured::new(Format::Json, "workspaces")),
+            ))
+            .run()
+            .unwrap();
+
+        assert!(dir.ends_with("node"));
+    }
+
+    #[test]
+    fn test_get_dir_by_target_file_toml_key() {
+        let dir: PathBuf = GetDir::new()
+            .dir(current_dir().unwrap().join("assets").join("structured"))
+            .target(Target::File(
+                FileTarget::new("pyproject.toml")
+                    .structured(Structured::new(Format::Toml, "tool.poetry")),
+            ))
+            .run()
+            .unwrap();
+
+        assert!(dir.ends_with("python"));
+    }
+
+    #[test]
+    fn test_get_dir_by_target_file_yaml_key_value() {
+        let target: Target = Target::File(
+            FileTarget::glob("*.yaml").structured(
+                Structured::new(Format::Yaml, "services.web.image")
+                    .value("nginx"),
+            ),
+        );
+
+        let dir: PathBuf = GetDir::new()
+            .dir(current_dir().unwrap().join("assets").join("structured"))
+            .target(target)
+            .run()
+            .unwrap();
+
+        assert!(dir.ends_with("compose"));
+
+        let result: Result<PathBuf, GetDirError> = GetDir::new()
+            .dir(current_dir().unwrap().join("assets").join("structured"))
+            .target(Target::File(
+                FileTarget::glob("*.yaml").structured(
+                    Structured::new(Format::Yaml, "services.web.image")
+                        .value("redis"),
+                ),
+            ))
+            .run();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_get_dir_by_target_file_min_size() {
+        let dir: PathBuf = GetDir::new()
+            .dir(current_dir().unwrap().join("assets").join("metadata"))
+            .target(Target::File(FileTarget::new(".env").min_size(1)))
+            .run()
+            .unwrap();
+
+        assert!(dir.ends_with("filled"));
+    }
+
+    #[test]
+    fn test_get_dir_by_target_file_modified_time() {
+        let dir: PathBuf =
+            current_dir().unwrap().join("assets").join("metadata");
+        let tomorrow: SystemTime =
+            SystemTime::now() + Duration::from_secs(24 * 60 * 60);
+
+        let result: Result<PathBuf, GetDirError> = GetDir::new()
+            .dir(&dir)
+            .target(Target::File(
+                FileTarget::new(".env").modified_after(tomorrow),
+            ))
+            .run();
+
+        assert!(result.is_err());
+
+        let result: Result<PathBuf, GetDirError> = GetDir::new()
+            .dir(&dir)
+            .target(Target::File(
+                FileTarget::new(".env").modified_before(tomorrow),
+            ))
+            .run();
+
+        assert!(result.is_ok());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_get_dir_by_target_file_executable() {
+        let dir: PathBuf = GetDir::new()
+            .dir(current_dir().unwrap().join("assets").join("metadata"))
+            .target(Target::File(FileTarget::glob("*").executable(true)))
+            .run()
+            .unwrap();
+
+        assert!(dir.ends_with("script"));
+    }
+
+    #[test]
+    fn test_get_dir_by_target_nested_file() {
+        let dir: PathBuf = GetDir::new()
+            .dir(current_dir().unwrap().join("assets"))
+            .target(Target::File(
+                FileTarget::relative("solution/app.sln").unwrap(),
+            ))
+            .run()
+            .unwrap();
+
+        assert!(dir.ends_with("glob"));
+    }
+
+    #[test]
+    fn test_relative_target_rejects_escaping_paths() {
+        assert!(FileTarget::relative("/etc/passwd").is_err());
+        assert!(FileTarget::relative("../Cargo.toml").is_err());
+        assert!(DirTarget::relative("src/../..").is_err());
+        assert!(DirTarget::relative("./src").is_ok());
+    }
+
+    #[test]
+    fn test_get_dir_with_escaping_target() {
+        let result: Result<PathBuf, GetDirError> = GetDir::new()
+            .target(Target::File(FileTarget::new("../Cargo.toml")))
+            .run_reverse();
+
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::InvalidInput);
+
+        let result: Result<PathBuf, GetDirError> =
+            GetDir::new().target(Target::Dir(DirTarget::glob("/tmp/*"))).run();
+
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn test_get_dir_by_target_ascii_case_insensitive() {
+        let dir: PathBuf = current_dir().unwrap().join("assets").join("case");
+
+        let result: Result<PathBuf, GetDirError> = GetDir::new()
+            .dir(&dir)
+            .target(Target::File(FileTarget::new("README.md")))
+            .run();
+
+        assert!(result.is_err());
+
+        let dir: PathBuf = GetDir::new()
+            .dir(&dir)
+            .target(Target::File(
+                FileTarget::new("README.md").case(Case::AsciiInsensitive),
+            ))
+            .run()
+            .unwrap();
+
+        assert!(dir.ends_with("readme"));
+    }
+
+    #[test]
+    fn test_get_dir_by_target_unicode_case_insensitive() {
+        let dir: PathBuf = current_dir().unwrap().join("assets").join("case");
+        let name: &str = "CAF\u{c9}.TXT";
+
+        let result: Result<PathBuf, GetDirError> = GetDir::new()
+            .dir(&dir)
+            .target(Target::File(
+                FileTarget::new(name).case(Case::AsciiInsensitive),
+            ))
+            .run();
+
+        assert!(result.is_err());
+
+        let dir: PathBuf = GetDir::new()
+            .dir(&dir)
+            .target(Target::File(FileTarget::new(name).case(Case::Insensitive)))
+            .run()
+            .unwrap();
+
+        assert!(dir.ends_with("accent"));
+    }
+
+    #[test]
+    fn test_get_dir_by_target_unicode_case_fold() {
+        let dir: PathBuf = current_dir().unwrap().join("assets").join("case");
+
+        for name in ["STRASSE.MD", "\u{3bf}\u{3b4}\u{3bf}\u{3c3}.txt"] {
+            let result: Result<PathBuf, GetDirError> = GetDir::new()
+                .dir(&dir)
+                .target(Target::File(
+                    FileTarget::new(name).case(Case::AsciiInsensitive),
+                ))
+                .run();
+
+            assert!(result.is_err());
+
+            let dir: PathBuf = GetDir::new()
+                .dir(&dir)
+                .target(Target::File(
+                    FileTarget::new(name).case(Case::Insensitive),
+                ))
+                .run()
+                .unwrap();
+
+            assert!(dir.ends_with("fold"));
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_get_dir_by_target_symlink() {
+        let dir: PathBuf = current_dir().unwrap().join("assets").join("entry");
+
+        let result: Result<PathBuf, GetDirError> = GetDir::new()
+            .dir(&dir)
+            .target(Target::Entry(
+                EntryTarget::new("42").kind(EntryKind::Symlink),
+            ))
+            .run();
+
+        assert!(result.is_err());
+
+        let dir: PathBuf = GetDir::new()
+            .dir(&dir)
+            .target(Target::Entry(
+                EntryTarget::new("current").kind(EntryKind::Symlink),
+            ))
+            .run()
+            .unwrap();
+
+        assert!(dir.ends_with("release"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_get_dir_by_target_socket() {
+        use std::{fs, os::unix::net::UnixListener};
+
+        let temp: TempDir = TempDir::new("socket");
+        let dir: PathBuf = temp.to_path_buf();
+        let run: PathBuf = dir.join("run");
+
+        fs::create_dir_all(&run).unwrap();
+
+        let _listener: UnixListener =
+            UnixListener::bind(run.join("app.sock")).unwrap();
+
+        let found: Result<PathBuf, GetDirError> = GetDir::new()
+            .dir(&dir)
+            .target(Target::Entry(
+                EntryTarget::glob("*.sock").kind(EntryKind::Socket),
+            ))
+            .run();
+
+        let any: Result<PathBuf, GetDirError> = GetDir::new()
+            .dir(&dir)
+            .target(Target::Entry(EntryTarget::new("app.sock")))
+            .run();
+
+        let file: Result<PathBuf, GetDirError> = GetDir::new()
+            .dir(&dir)
+            .target(Target::File(FileTarget::new("app.sock")))
+            .run();
+
+        assert_eq!(found.unwrap(), run);
+        assert_eq!(any.unwrap(), run);
+        assert!(file.is_err());
+    }
+
+    #[test]
+    fn test_get_dir_all() {
+        let root: PathBuf =
+            current_dir().unwrap().join("assets").join("monorepo");
+
+        let dirs: Vec<PathBuf> = GetDir::new()
+            .dir(&root)
+            .target(Target::File(FileTarget::new("package.json")))
+            .run_all()
+            .unwrap();
+
+        assert_eq!(dirs.len(), 3);
+        assert!(dirs.contains(&root.join("app")));
+        assert!(dirs.contains(&root.join("lib")));
+        assert_eq!(dirs[2], root.join("app").join("nested"));
+
+        let none: Vec<PathBuf> = GetDir::new()
+            .dir(&root)
+            .target(Target::File(FileTarget::new("Cargo.toml")))
+            .run_all()
+            .unwrap();
+
+        assert!(none.is_empty());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_get_dir_all_without_duplicates() {
+        let dirs: Vec<PathBuf> = GetDir::new()
+            .dir(current_dir().unwrap().join("assets").join("entry"))
+            .target(Target::File(FileTarget::new(".gitkeep")))
+            .run_all()
+            .unwrap();
+
+        assert_eq!(dirs.len(), 1);
+    }
+
+    #[test]
+    fn test_get_dir_iter() {
+        let root: PathBuf =
+            current_dir().unwrap().join("assets").join("monorepo");
+
+        let matches: Vec<Match> = GetDir::new()
+            .dir(&root)
+            .target(Target::File(FileTarget::new("package.json")))
+            .iter()
+            .take(2)
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(matches.len(), 2);
+        assert!(matches.iter().all(|m| m.depth == 1));
+
+        let nested: Match = GetDir::new()
+            .dir(&root)
+            .target(Target::File(FileTarget::new("package.json")))
+            .iter()
+            .flatten()
+            .find(|m| m.depth == 2)
+            .unwrap();
+
+        assert_eq!(nested.dir, root.join("app").join("nested"));
+    }
+
+    #[test]
+    fn test_get_dir_iter_with_invalid_target() {
+        let mut iter = GetDir::new()
+            .target(Target::File(FileTarget::new("../Cargo.toml")))
+            .iter();
+
+        assert_eq!(
+            iter.next().unwrap().unwrap_err().kind(),
+            io::ErrorKind::InvalidInput
+        );
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn test_get_dir_iter_reverse() {
+        let matches: Vec<Match> = GetDir::new()
+            .target(Target::File(FileTarget::new("Cargo.toml")))
+            .iter_reverse()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(matches[0].dir, current_dir().unwrap());
+        assert_eq!(matches[0].depth, 0);
+        assert_eq!(
+            matches[1].dir,
+            current_dir().unwrap().join("..").canonicalize().unwrap()
+        );
+        assert_eq!(matches[1].depth, 1);
+
+        let limited: Vec<Match> = GetDir::new()
+            .target(Target::File(FileTarget::new("Cargo.toml")))
+            .depth(1)
+            .iter_reverse()
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(limited.len(), 1);
+    }
+
+    #[test]
+    fn test_get_dir_detailed() {
+        let root: PathBuf =
+            current_dir().unwrap().join("assets").join("monorepo");
+
+        let found: Match = GetDir::new()
+            .dir(&root)
+            .targets([
+                Target::Dir(DirTarget::new("node_modules")),
+                Target::File(FileTarget::new("package.json")),
+            ])
+            .run_detailed()
+            .unwrap();
+
+        assert_eq!(found.target, 1);
+        assert_eq!(found.path, found.dir.join("package.json"));
+        assert_eq!(found.depth, 1);
+        assert_eq!(found.direction, Direction::Forward);
+
+        let found: Match = GetDir::new()
+            .dir(root.join("app"))
+            .target(Target::File(FileTarget::new("package.json")))
+            .run_detailed()
+            .unwrap();
+
+        assert_eq!(found.dir, root.join("app"));
+        assert_eq!(found.depth, 0);
+    }
+
+    #[test]
+    fn test_get_dir_reverse_detailed() {
+        let found: Match = GetDir::new()
+            .dir(current_dir().unwrap().join("assets"))
+            .targets([
+                Target::Dir(DirTarget::new("node_modules")),
+                Target::File(FileTarget::new("Cargo.toml")),
+            ])
+            .run_reverse_detailed()
+            .unwrap();
+
+        assert_eq!(found.dir, current_dir().unwrap());
+        assert_eq!(found.target, 1);
+        assert_eq!(found.path, current_dir().unwrap().join("Cargo.toml"));
+        assert_eq!(found.depth, 1);
+        assert_eq!(found.direction, Direction::Reverse);
+
+        let found: Result<Match, GetDirError> = GetDir::new()
+            .target(Target::Dir(DirTarget::new("node_modules")))
+            .depth(2)
+            .run_reverse_detailed();
+
+        assert_eq!(found.unwrap_err().kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn test_get_dir_reverse_outermost() {
+        let dir: PathBuf = GetDir::new()
+            .target(Target::File(FileTarget::new("Cargo.toml")))
+            .depth(2)
+            .run_reverse_outermost()
+            .unwrap();
+
+        let content: String = read_to_string(dir.join("Cargo.toml")).unwrap();
+
+        assert!(content.contains("[workspace.dependencies]"));
+
+        let dir: PathBuf = GetDir::new()
+            .target(Target::File(FileTarget::new("Cargo.toml")))
+            .depth(1)
+            .run_reverse_outermost()
+            .unwrap();
+
+        assert_eq!(dir, current_dir().unwrap());
+    }
+
+    #[test]
+    fn test_get_dir_with_strategy() {
+        let root: PathBuf =
+            current_dir().unwrap().join("assets").join("monorepo");
+
+        let search = |strategy: Strategy, depth: usize| -> Vec<PathBuf> {
+            GetDir::new()
+                .dir(&root)
+                .target(Target::File(FileTarget::new("package.json")))
+                .strategy(strategy)
+                .depth(depth)
+                .run_all()
+                .unwrap()
+        };
+
+        let dfs: Vec<PathBuf> = search(Strategy::Dfs, usize::MAX);
+        let app: usize = dfs.iter().position(|d| d.ends_with("app")).unwrap();
+
+        assert_eq!(dfs.len(), 3);
+        assert_eq!(dfs[app + 1], root.join("app").join("nested"));
+
+        let deepening: Vec<PathBuf> =
+            search(Strategy::IterativeDeepening, usize::MAX);
+
+        assert_eq!(deepening.len(), 3);
+        assert_eq!(deepening[2], root.join("app").join("nested"));
+
+        for strategy in
+            [Strategy::Bfs, Strategy::Dfs, Strategy::IterativeDeepening]
+        {
+            assert_eq!(search(strategy, 2).len(), 2);
+            assert!(search(strategy, 0).is_empty());
+        }
+    }
+
+    #[test]
+    fn test_get_dir_nearest() {
+        let root: PathBuf =
+            current_dir().unwrap().join("assets").join("monorepo");
+
+        let search = |up: usize, down: usize| -> Result<PathBuf, GetDirError> {
+            GetDir::new()
+                .dir(root.join("lib"))
+                .target(Target::File(FileTarget::new(".gitkeep")))
+                .up_depth(up)
+                .down_depth(down)
+                .run_nearest()
+        };
+
+        assert_eq!(search(usize::MAX, usize::MAX).unwrap(), root.join("docs"));
+        assert!(search(1, usize::MAX).is_err());
+        assert!(search(usize::MAX, 1).is_err());
+
+        let dir: PathBuf = GetDir::new()
+            .dir(root.join("app"))
+            .target(Target::File(FileTarget::new("package.json")))
+            .run_nearest()
+            .unwrap();
+
+        assert_eq!(dir, root.join("app"));
+    }
+
+    #[test]
+    fn test_get_dir_nearest_with_depth_limit() {
+        let root: PathBuf =
+            current_dir().unwrap().join("assets").join("monorepo");
+
+        let search = |name: &str, up: usize, down: usize| {
+            GetDir::new()
+                .dir(root.join(name))
+                .target(Target::File(FileTarget::new("package.json")))
+                .up_depth(up)
+                .down_depth(down)
+                .run_nearest()
+        };
+
+        assert_eq!(search("app", 1, 1).unwrap(), root.join("app"));
+        assert!(search("app", 0, 1).is_err());
+        assert!(search("app", 1, 0).is_err());
+        assert!(search("docs", 1, usize::MAX).is_err());
+        assert!(search("docs", 2, 1).is_err());
+        assert!(search("docs", 2, 2).is_ok());
+        assert!(search("", 1, 1).is_err());
+        assert!(search("", 1, 2).is_ok());
+    }
+
+    #[test]
+    fn test_get_dir_reverse_with_boundary() {
+        let root: PathBuf =
+            current_dir().unwrap().join("assets").join("monorepo");
+        let nested: PathBuf = root.join("app").join("nested");
+
+        let search =
+            |boundary: Option<Boundary>| -> Result<Outcome, GetDirError> {
+                GetDir::new()
+                    .dir(&nested)
+                    .target(Target::File(FileTarget::new("LICENSE")))
+                    .boundaries(boundary)
+                    .run_reverse_bounded()
+            };
+
+        assert_eq!(
+            search(Some(Boundary::Path(root.clone()))).unwrap(),
+            Outcome::Boundary(root.clone())
+        );
+        assert_eq!(
+            search(Some(Boundary::Target(Target::File(FileTarget::new(
+                "package.json"
+            )))))
+            .unwrap(),
+            Outcome::Boundary(nested.clone())
+        );
+        assert!(matches!(search(None).unwrap(), Outcome::Found(_)));
+
+        let found: Result<PathBuf, GetDirError> = GetDir::new()
+            .dir(&nested)
+            .target(Target::File(FileTarget::new("LICENSE")))
+            .boundary(Boundary::Path(root.clone()))
+            .run_reverse();
+
+        assert!(found.is_err());
+
+        let found: PathBuf = GetDir::new()
+            .dir(&nested)
+            .target(Target::File(FileTarget::new("package.json")))
+            .boundary(Boundary::Path(root.clone()))
+            .run_reverse_outermost()
+            .unwrap();
+
+        assert_eq!(found, root.join("app"));
+
+        let outcome: Outcome = GetDir::new()
+            .dir(&nested)
+            .target(Target::File(FileTarget::new("LICENSE")))
+            .depth(1)
+            .run_reverse_bounded()
+            .unwrap();
+
+        assert_eq!(outcome, Outcome::Depth);
+
+        let outcome: Outcome = GetDir::new()
+            .dir(&nested)
+            .target(Target::File(FileTarget::new("get_dir_missing_marker")))
+            .run_reverse_bounded()
+            .unwrap();
+
+        assert_eq!(outcome, Outcome::Root);
+    }
+
+    #[test]
+    fn test_get_dir_same_file_system() {
+        let dirs: Vec<PathBuf> = GetDir::new()
+            .dir(current_dir().unwrap().join("assets").join("monorepo"))
+            .target(Target::File(FileTarget::new("package.json")))
+            .same_file_system(true)
+            .run_all()
+            .unwrap();
+
+        assert_eq!(dirs.len(), 3);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_get_dir_same_file_system_with_mount_point() {
+        let search = |same_file_system: bool| -> Outcome {
+            GetDir::new()
+                .dir("/proc")
+                .target(Target::Dir(DirTarget::new("proc")))
+                .same_file_system(same_file_system)
+                .run_reverse_bounded()
+                .unwrap()
+        };
+
+        assert_eq!(search(false), Outcome::Found(PathBuf::from("/")));
+        assert_eq!(search(true), Outcome::Boundary(PathBuf::from("/proc")));
+
+        let found: Result<PathBuf, GetDirError> = GetDir::new()
+            .dir("/")
+            .target(Target::File(FileTarget::new("cpuinfo")))
+            .depth(2)
+            .same_file_system(true)
+            .run();
+
+        assert!(found.is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_get_dir_follow_links() {
+        use std::{fs, os::unix::fs::symlink};
+
+        let temp: TempDir = TempDir::new("follow_links");
+        let dir: PathBuf = temp.to_path_buf();
+        let project: PathBuf = dir.join("project");
+
+        fs::create_dir_all(dir.join("real").join("nested")).unwrap();
+        fs::create_dir_all(project.join("sub")).unwrap();
+        fs::write(dir.join("real").join("nested").join("marker"), "").unwrap();
+        fs::write(project.join("sub").join("marker"), "").unwrap();
+        symlink(dir.join("real"), project.join("link")).unwrap();
+        symlink(&dir, project.join("loop")).unwrap();
+        symlink(&project, dir.join("root")).unwrap();
+
+        let search = |start: &PathBuf, follow_links: FollowLinks| {
+            GetDir::new()
+                .dir(start)
+                .target(Target::File(FileTarget::new("marker")))
+                .follow_links(follow_links)
+                .run_all()
+                .unwrap()
+        };
+
+        let always: Vec<PathBuf> = search(&project, FollowLinks::Always);
+        let never: Vec<PathBuf> = search(&project, FollowLinks::Never);
+        let root_never: Vec<PathBuf> =
+            search(&dir.join("root"), FollowLinks::Never);
+        let root_only: Vec<PathBuf> =
+            search(&dir.join("root"), FollowLinks::OnlyRoot);
+
+        assert_eq!(always.len(), 2);
+        assert_eq!(never, vec![project.join("sub")]);
+        assert!(root_never.is_empty());
+        assert_eq!(root_only, vec![dir.join("root").join("sub")]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_get_dir_follow_links_before_real_path() {
+        use std::{fs, os::unix::fs::symlink};
+
+        let temp: TempDir = TempDir::new("follow_links_order");
+        let dir: PathBuf = temp.to_path_buf();
+
+        fs::create_dir_all(dir.join("a").join("c")).unwrap();
+        fs::create_dir_all(dir.join("b")).unwrap();
+        fs::write(dir.join("a").join("c").join("marker"), "").unwrap();
+        symlink(dir.join("a"), dir.join("b").join("link")).unwrap();
+
+        for strategy in
+            [Strategy::Bfs, Strategy::Dfs, Strategy::IterativeDeepening]
+        {
+            let dirs: Vec<PathBuf> = GetDir::new()
+                .dir(&dir)
+                .target(Target::File(FileTarget::new("marker")))
+                .depth(3)
+                .strategy(strategy)
+                .run_all()
+                .unwrap();
+
+            assert_eq!(dirs, vec![dir.join("a").join("c")], "{strategy:?}");
+        }
+    }
+
+    #[test]
+    fn test_get_dir_nearest_skip_hidden() {
+        let dir: PathBuf = current_dir().unwrap().join("assets").join("hidden");
+
+        let search = |skip_hidden: bool| {
+            GetDir::new()
+                .dir(&dir)
+                .target(Target::File(FileTarget::new("marker.txt")))
+                .exclude(["app"])
+                .skip_hidden(skip_hidden)
+                .up_depth(1)
+                .run_nearest()
+        };
+
+        assert_eq!(search(false).unwrap(), dir.join(".cache"));
+        assert!(search(true).is_err());
+    }
+
+    #[test]
+    fn test_get_dir_respect_ignore_files() {
+        use std::fs;
+
+        let temp: TempDir = TempDir::new("ignore_files");
+        let dir: PathBuf = temp.to_path_buf();
+
+        for sub in ["app/target", "excluded", "lib/target", "target"] {
+            fs::create_dir_all(dir.join(sub)).unwrap();
+            fs::write(dir.join(sub).join("marker"), "").unwrap();
+        }
+
+        fs::create_dir_all(dir.join(".git").join("info")).unwrap();
+        fs::write(dir.join(".git").join("info").join("exclude"), "excluded\n")
+            .unwrap();
+        fs::write(dir.join(".gitignore"), "target/\n").unwrap();
+        fs::write(dir.join("app").join(".ignore"), "!target/\n").unwrap();
+
+        let search = |start: PathBuf, respect_ignore_files: bool| {
+            let mut dirs: Vec<PathBuf> = GetDir::new()
+                .dir(start)
+                .target(Target::File(FileTarget::new("marker")))
+                .respect_ignore_files(respect_ignore_files)
+                .run_all()
+                .unwrap();
+
+            dirs.sort();
+            dirs
+        };
+
+        let all: Vec<PathBuf> = search(dir.clone(), false);
+        let respected: Vec<PathBuf> = search(dir.clone(), true);
+        let nested: Vec<PathBuf> = search(dir.join("lib"), true);
+
+        assert_eq!(all.len(), 4);
+        assert_eq!(respected, vec![dir.join("app").join("target")]);
+        assert!(nested.is_empty());
+    }
+
+    #[test]
+    fn test_get_dir_nearest_respect_ignore_files() {
+        use std::fs;
+
+        let temp: TempDir = TempDir::new("nearest_ignore_files");
+        let dir: PathBuf = temp.to_path_buf();
+
+        fs::create_dir_all(dir.join(".git")).unwrap();
+        fs::create_dir_all(dir.join("app")).unwrap();
+        fs::create_dir_all(dir.join("target")).unwrap();
+        fs::write(dir.join("target").join("marker"), "").unwrap();
+        fs::write(dir.join(".gitignore"), "target/\n").unwrap();
+
+        let search = |respect_ignore_files: bool| {
+            GetDir::new()
+                .dir(dir.join("app"))
+                .target(Target::File(FileTarget::new("marker")))
+                .respect_ignore_files(respect_ignore_files)
+                .up_depth(2)
+                .run_nearest()
+        };
+
+        assert_eq!(search(false).unwrap(), dir.join("target"));
+        assert!(search(true).is_err());
+    }
+
+    #[test]
+    fn test_get_dir_exclude_and_include_only() {
+        let root: PathBuf =
+            current_dir().unwrap().join("assets").join("monorepo");
+
+        let search = |exclude: &[&str], include_only: &[&str]| {
+            GetDir::new()
+                .dir(&root)
+                .target(Target::File(FileTarget::new("package.json")))
+                .exclude(exclude.iter().copied())
+                .include_only(include_only.iter().copied())
+                .run_all()
+        };
+
+        assert_eq!(search(&["app"], &[]).unwrap(), vec![root.join("lib")]);
+        assert_eq!(search(&["nested"], &[]).unwrap().len(), 2);
+        assert_eq!(search(&["app/*"], &[]).unwrap().len(), 2);
+        assert_eq!(
+            search(&[], &["app/*"]).unwrap(),
+            vec![root.join("app").join("nested")]
+        );
+        assert_eq!(search(&[], &["app"]).unwrap(), vec![root.join("app")]);
+        assert_eq!(
+            search(&["/tmp"], &[]).unwrap_err().kind(),
+            io::ErrorKind::InvalidInput
+        );
+    }
+
+    #[test]
+    fn test_get_dir_nearest_with_exclude_and_include_only() {
+        let root: PathBuf =
+            current_dir().unwrap().join("assets").join("monorepo");
+
+        let search = |exclude: &[&str], include_only: &[&str]| {
+            GetDir::new()
+                .dir(root.join("docs"))
+                .target(Target::File(FileTarget::new("package.json")))
+                .exclude(exclude.iter().copied())
+                .include_only(include_only.iter().copied())
+                .up_depth(2)
+                .run_nearest()
+        };
+
+        assert_eq!(search(&["app"], &[]).unwrap(), root.join("lib"));
+        assert_eq!(search(&["lib"], &[]).unwrap(), root.join("app"));
+        assert!(search(&["app", "lib"], &[]).is_err());
+        assert_eq!(
+            search(&[], &["*/nested"]).unwrap(),
+            root.join("app").join("nested")
+        );
+    }
+
+    #[test]
+    fn test_get_dir_skip_hidden() {
+        let dir: PathBuf = current_dir().unwrap().join("assets").join("hidden");
+
+        let search = |start: PathBuf, skip_hidden: bool| {
+            let mut dirs: Vec<PathBuf> = GetDir::new()
+                .dir(start)
+                .target(Target::File(FileTarget::new("marker.txt")))
+                .skip_hidden(skip_hidden)
+                .run_all()
+                .unwrap();
+
+            dirs.sort();
+            dirs
+        };
+
+        let hidden: Result<PathBuf, GetDirError> = GetDir::new()
+            .dir(&dir)
+            .target(Target::Dir(DirTarget::new(".cache")))
+            .skip_hidden(true)
+            .run();
+
+        assert_eq!(
+            search(dir.clone(), false),
+            vec![dir.join(".cache"), dir.join("app")]
+        );
+        assert_eq!(search(dir.clone(), true), vec![dir.join("app")]);
+        assert_eq!(search(dir.join(".cache"), true), vec![dir.join(".cache")]);
+        assert_eq!(hidden.unwrap(), dir);
+    }
+
+    #[test]
+    fn test_get_dir_error_not_found() {
+        let root: PathBuf =
+            current_dir().unwrap().join("assets").join("monorepo");
+
+        let error: GetDirError = GetDir::new()
+            .dir(&root)
+            .target(Target::Dir(DirTarget::new("node_modules")))
+            .depth(2)
+            .run()
+            .unwrap_err();
+
+        assert!(matches!(
+            error,
+            GetDirError::NotFound { visited: 4, depth: 1, skipped: 0 }
+        ));
+
+        let error: GetDirError = GetDir::new()
+            .dir(root.join("app"))
+            .target(Target::Dir(DirTarget::new("node_modules")))
+            .depth(2)
+            .run_reverse()
+            .unwrap_err();
+
+        assert!(matches!(
+            error,
+            GetDirError::NotFound { visited: 2, depth: 1, skipped: 0 }
+        ));
+        assert_eq!(io::Error::from(error).kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn test_get_dir_error() {
+        let dir: PathBuf = current_dir().unwrap().join("missing");
+
+        let error: GetDirError = GetDir::new()
+            .dir(&dir)
+            .target(Target::File(FileTarget::new("Cargo.toml")))
+            .run()
+            .unwrap_err();
+
+        assert!(matches!(&error, GetDirError::Io { path, .. } if *path == dir));
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+        assert!(std::error::Error::source(&error).is_some());
+
+        let search = || {
+            GetDir::new()
+                .dir(&dir)
+                .target(Target::Dir(DirTarget::new("node_modules")))
+        };
+        let is_missing = |error: GetDirError| match error {
+            | GetDirError::Io { path, .. } => path == dir,
+            | _ => false,
+        };
+
+        assert!(is_missing(search().run_all().unwrap_err()));
+        assert!(is_missing(search().run_reverse().unwrap_err()));
+        assert!(is_missing(search().run_reverse_bounded().unwrap_err()));
+        assert!(is_missing(search().run_reverse_outermost().unwrap_err()));
+        assert!(is_missing(search().up_depth(0).run_nearest().unwrap_err()));
+
+        let mut iter = search().iter_reverse();
+
+        assert!(is_missing(iter.next().unwrap().unwrap_err()));
+        assert!(iter.next().is_none());
+
+        let error: GetDirError = GetDir::new()
+            .target(Target::File(FileTarget::new("../Cargo.toml")))
+            .run_nearest()
+            .unwrap_err();
+
+        assert!(matches!(error, GetDirError::InvalidTarget { .. }));
+        assert_eq!(io::Error::from(error).kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn test_get_dir_timeout() {
+        let search = |timeout: Duration| {
+            GetDir::new()
+                .dir(current_dir().unwrap().join("assets").join("monorepo"))
+                .target(Target::File(FileTarget::new("package.json")))
+                .timeout(timeout)
+        };
+
+        let is_timeout =
+            |error: GetDirError| matches!(error, GetDirError::Timeout);
+
+        assert!(is_timeout(search(Duration::ZERO).run().unwrap_err()));
+        assert!(is_timeout(search(Duration::ZERO).run_all().unwrap_err()));
+        assert!(is_timeout(search(Duration::ZERO).run_reverse().unwrap_err()));
+        assert!(is_timeout(search(Duration::ZERO).run_nearest().unwrap_err()));
+        assert_eq!(
+            search(Duration::ZERO).run().unwrap_err().kind(),
+            io::ErrorKind::TimedOut
+        );
+        assert_eq!(search(Duration::MAX).run_all().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_get_dir_cancel_token() {
+        let token: CancelToken = CancelToken::new();
+
+        let search = || {
+            GetDir::new()
+                .dir(current_dir().unwrap().join("assets").join("monorepo"))
+                .target(Target::File(FileTarget::new("package.json")))
+                .cancel_token(token.clone())
+        };
+
+        let mut iter = search().iter();
+
+        assert!(iter.next().unwrap().is_ok());
+
+        token.cancel();
+
+        assert!(matches!(
+            iter.next().unwrap().unwrap_err(),
+            GetDirError::Cancelled
+        ));
+        assert!(iter.next().is_none());
+
+        let error: GetDirError = search().run_reverse_outermost().unwrap_err();
+
+        assert!(matches!(error, GetDirError::Cancelled));
+        assert_eq!(error.kind(), io::ErrorKind::Interrupted);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_get_dir_error_skipped() {
+        use std::{fs, os::unix::fs::PermissionsExt as _};
+
+        let temp: TempDir = TempDir::new("skipped");
+        let dir: PathBuf = temp.to_path_buf();
+        let locked: PathBuf = dir.join("locked");
+
+        fs::create_dir_all(locked.join("inner")).unwrap();
+        fs::set_permissions(&locked, fs::Permissions::from_mode(0o000))
+            .unwrap();
+
+        let search = || {
+            GetDir::new()
+                .dir(&dir)
+                .target(Target::Dir(DirTarget::new("node_modules")))
+        };
+
+        // Permissions do not apply to a privileged user.
+        let is_locked: bool = fs::read_dir(&locked).is_err();
+        let forward: GetDirError = search().run().unwrap_err();
+        let nearest: GetDirError =
+            search().up_depth(1).run_nearest().unwrap_err();
+
+        fs::set_permissions(&locked, fs::Permissions::from_mode(0o755))
+            .unwrap();
+
+        if is_locked {
+            assert!(matches!(
+                forward,
+                GetDirError::NotFound { visited: 2, depth: 1, skipped: 1 }
+            ));
+            assert!(matches!(
+                nearest,
+                GetDirError::NotFound { visited: 2, depth: 1, skipped: 1 }
+            ));
+        }
+    }
 }
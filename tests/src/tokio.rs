@@ -1,12 +1,14 @@
 #[cfg(test)]
 mod tests {
 
-    use std::{env::current_dir, path::PathBuf};
+    use std::{env::current_dir, path::PathBuf, pin::pin, time::Duration};
 
     use tokio::fs::read_to_string;
 
+    use futures_util::StreamExt as _;
     use get_dir::{
-        DirTarget, FileTarget, GetDir, Target, tokio::GetDirAsyncExt,
+        Boundary, CancelToken, DirTarget, Direction, FileTarget, GetDir,
+        GetDirError, Match, Outcome, Strategy, Target, tokio::GetDirAsyncExt,
     };
 
     #[tokio::test]
@@ -80,6 +82,7 @@ mod tests {
     }
 
     #[tokio::test]
+    #[allow(clippy::redundant_pattern_matching)]
     async fn test_get_dir_with_depth_limit() {
         let dir: PathBuf = current_dir().unwrap();
         let target: Target = Target::File(FileTarget::new("lib.rs"));
@@ -100,6 +103,7 @@ mod tests {
     }
 
     #[tokio::test]
+    #[allow(clippy::redundant_pattern_matching)]
     async fn test_get_dir_reverse_with_depth_limit() {
         let dir: PathBuf = current_dir().unwrap();
         let target: Target = Target::File(FileTarget::new("Cargo.lock"));
@@ -120,4 +124,290 @@ mod tests {
             panic!("Should succeed");
         }
     }
+
+    #[tokio::test]
+    async fn test_get_dir_by_target_file_glob() {
+        let dir: PathBuf = GetDir::new()
+            .dir(current_dir().unwrap().join("assets").join("glob"))
+            .target(Target::File(FileTarget::glob("*.sln")))
+            .run_async()
+            .await
+            .unwrap();
+
+        assert!(dir.ends_with("solution"));
+    }
+
+    #[tokio::test]
+    async fn test_get_dir_by_target_file_regex() {
+        let dir: PathBuf = GetDir::new()
+            .dir(current_dir().unwrap().join("assets").join("regex"))
+            .target(Target::File(FileTarget::regex(r"^Dockerfile(\..+)?$")))
+            .run_async()
+            .await
+            .unwrap();
+
+        assert!(dir.ends_with("app"));
+    }
+
+    #[tokio::test]
+    async fn test_get_dir_by_target_file_content() {
+        let dir: PathBuf = GetDir::new()
+            .target(Target::File(
+                FileTarget::new("Cargo.toml").contains("[workspace]"),
+            ))
+            .run_reverse_async()
+            .await
+            .unwrap();
+
+        let content: String =
+            read_to_string(dir.join("Cargo.toml")).await.unwrap();
+
+        assert!(content.contains("[workspace.dependencies]"));
+    }
+
+    #[tokio::test]
+    async fn test_get_dir_by_target_file_min_size() {
+        let dir: PathBuf = GetDir::new()
+            .dir(current_dir().unwrap().join("assets").join("metadata"))
+            .target(Target::File(FileTarget::new(".env").min_size(1)))
+            .run_async()
+            .await
+            .unwrap();
+
+        assert!(dir.ends_with("filled"));
+    }
+
+    #[tokio::test]
+    async fn test_get_dir_all() {
+        let root: PathBuf =
+            current_dir().unwrap().join("assets").join("monorepo");
+
+        let dirs: Vec<PathBuf> = GetDir::new()
+            .dir(&root)
+            .target(Target::File(FileTarget::new("package.json")))
+            .run_all_async()
+            .await
+            .unwrap();
+
+        assert_eq!(dirs.len(), 3);
+        assert_eq!(dirs[2], root.join("app").join("nested"));
+    }
+
+    #[tokio::test]
+    async fn test_get_dir_stream() {
+        let root: PathBuf =
+            current_dir().unwrap().join("assets").join("monorepo");
+
+        let mut stream = pin!(
+            GetDir::new()
+                .dir(&root)
+                .target(Target::File(FileTarget::new("package.json")))
+                .stream_async()
+        );
+
+        let first: Match = stream.next().await.unwrap().unwrap();
+
+        assert_eq!(first.depth, 1);
+
+        let rest: Vec<Match> =
+            stream.map(|found| found.unwrap()).collect().await;
+
+        assert_eq!(rest.len(), 2);
+        assert_eq!(rest[1].dir, root.join("app").join("nested"));
+    }
+
+    #[tokio::test]
+    async fn test_get_dir_stream_reverse() {
+        let matches: Vec<Match> = GetDir::new()
+            .target(Target::File(FileTarget::new("Cargo.toml")))
+            .stream_reverse_async()
+            .map(|found| found.unwrap())
+            .collect()
+            .await;
+
+        assert_eq!(matches[0].dir, current_dir().unwrap());
+        assert_eq!(matches[1].depth, 1);
+    }
+
+    #[tokio::test]
+    async fn test_get_dir_detailed() {
+        let found: Match = GetDir::new()
+            .dir(current_dir().unwrap().join("assets"))
+            .targets([
+                Target::Dir(DirTarget::new("node_modules")),
+                Target::File(FileTarget::new("Cargo.toml")),
+            ])
+            .run_reverse_detailed_async()
+            .await
+            .unwrap();
+
+        assert_eq!(found.target, 1);
+        assert_eq!(found.depth, 1);
+        assert_eq!(found.direction, Direction::Reverse);
+
+        let found: Match = GetDir::new()
+            .dir(current_dir().unwrap())
+            .target(Target::File(FileTarget::new("Cargo.toml")))
+            .run_detailed_async()
+            .await
+            .unwrap();
+
+        assert_eq!(found.path, current_dir().unwrap().join("Cargo.toml"));
+        assert_eq!(found.direction, Direction::Forward);
+    }
+
+    #[tokio::test]
+    async fn test_get_dir_reverse_outermost() {
+        let dir: PathBuf = GetDir::new()
+            .target(Target::File(FileTarget::new("Cargo.toml")))
+            .depth(2)
+            .run_reverse_outermost_async()
+            .await
+            .unwrap();
+
+        let content: String =
+            read_to_string(dir.join("Cargo.toml")).await.unwrap();
+
+        assert!(content.contains("[workspace.dependencies]"));
+    }
+
+    #[tokio::test]
+    async fn test_get_dir_with_strategy() {
+        let root: PathBuf =
+            current_dir().unwrap().join("assets").join("monorepo");
+
+        let dirs: Vec<PathBuf> = GetDir::new()
+            .dir(&root)
+            .target(Target::File(FileTarget::new("package.json")))
+            .strategy(Strategy::IterativeDeepening)
+            .run_all_async()
+            .await
+            .unwrap();
+
+        assert_eq!(dirs.len(), 3);
+        assert_eq!(dirs[2], root.join("app").join("nested"));
+    }
+
+    #[tokio::test]
+    async fn test_get_dir_nearest() {
+        let root: PathBuf =
+            current_dir().unwrap().join("assets").join("monorepo");
+
+        let dir: PathBuf = GetDir::new()
+            .dir(root.join("lib"))
+            .target(Target::File(FileTarget::new(".gitkeep")))
+            .run_nearest_async()
+            .await
+            .unwrap();
+
+        assert_eq!(dir, root.join("docs"));
+    }
+
+    #[tokio::test]
+    async fn test_get_dir_reverse_with_boundary() {
+        let root: PathBuf =
+            current_dir().unwrap().join("assets").join("monorepo");
+
+        let outcome: Outcome = GetDir::new()
+            .dir(root.join("app").join("nested"))
+            .target(Target::File(FileTarget::new("LICENSE")))
+            .boundary(Boundary::Path(root.clone()))
+            .run_reverse_bounded_async()
+            .await
+            .unwrap();
+
+        assert_eq!(outcome, Outcome::Boundary(root));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[tokio::test]
+    async fn test_get_dir_same_file_system_with_mount_point() {
+        let outcome: Outcome = GetDir::new()
+            .dir("/proc")
+            .target(Target::Dir(DirTarget::new("proc")))
+            .same_file_system(true)
+            .run_reverse_bounded_async()
+            .await
+            .unwrap();
+
+        assert_eq!(outcome, Outcome::Boundary(PathBuf::from("/proc")));
+    }
+
+    #[tokio::test]
+    async fn test_get_dir_respect_ignore_files() {
+        let root: PathBuf =
+            current_dir().unwrap().join("assets").join("monorepo");
+
+        let dirs: Vec<PathBuf> = GetDir::new()
+            .dir(&root)
+            .target(Target::File(FileTarget::new("package.json")))
+            .respect_ignore_files(true)
+            .run_all_async()
+            .await
+            .unwrap();
+
+        assert_eq!(dirs.len(), 2);
+        assert!(!dirs.contains(&root.join("lib")));
+    }
+
+    #[tokio::test]
+    async fn test_get_dir_error() {
+        let error: GetDirError = GetDir::new()
+            .dir(current_dir().unwrap().join("assets").join("monorepo"))
+            .target(Target::Dir(DirTarget::new("node_modules")))
+            .depth(2)
+            .run_async()
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            error,
+            GetDirError::NotFound { visited: 4, depth: 1, skipped: 0 }
+        ));
+
+        let error: GetDirError = GetDir::new()
+            .target(Target::File(FileTarget::new("../Cargo.toml")))
+            .run_reverse_async()
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, GetDirError::InvalidTarget { .. }));
+
+        let error: GetDirError = GetDir::new()
+            .dir(current_dir().unwrap().join("missing"))
+            .target(Target::Dir(DirTarget::new("node_modules")))
+            .run_all_async()
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, GetDirError::Io { .. }));
+
+        let error: GetDirError = GetDir::new()
+            .target(Target::Dir(DirTarget::new("node_modules")))
+            .timeout(Duration::ZERO)
+            .run_nearest_async()
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, GetDirError::Timeout));
+
+        let token: CancelToken = CancelToken::new();
+        let mut stream = pin!(
+            GetDir::new()
+                .dir(current_dir().unwrap().join("assets").join("monorepo"))
+                .target(Target::File(FileTarget::new("package.json")))
+                .cancel_token(token.clone())
+                .stream_async()
+        );
+
+        assert!(stream.next().await.unwrap().is_ok());
+
+        token.cancel();
+
+        assert!(matches!(
+            stream.next().await.unwrap(),
+            Err(GetDirError::Cancelled)
+        ));
+        assert!(stream.next().await.is_none());
+    }
 }